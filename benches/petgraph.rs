@@ -0,0 +1,57 @@
+//! Compares the strongly connected components computation on petgraph's
+//! `Graph` and `Csr` representations of the same graph.
+//!
+//! Run with `cargo bench --features petgraph`.
+use std::time::{Duration, Instant};
+
+use petgraph::{csr::Csr, graph::DiGraph};
+use scc_trait::Scc;
+
+const VERTICES: u32 = 10_000;
+const OUT_DEGREE: u32 = 4;
+const ITERATIONS: u32 = 10;
+
+/// Generates a pseudo-random edge list, sorted by source vertex.
+fn edges() -> Vec<(u32, u32)> {
+	let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+	let mut edges = Vec::with_capacity((VERTICES * OUT_DEGREE) as usize);
+
+	for source in 0..VERTICES {
+		for _ in 0..OUT_DEGREE {
+			state ^= state << 13;
+			state ^= state >> 7;
+			state ^= state << 17;
+			edges.push((source, (state % VERTICES as u64) as u32))
+		}
+	}
+
+	edges.sort_unstable();
+	edges.dedup();
+	edges
+}
+
+fn bench<G: Scc>(name: &str, graph: &G) {
+	let mut total = Duration::ZERO;
+	let mut len = 0;
+
+	for _ in 0..ITERATIONS {
+		let start = Instant::now();
+		len = graph.strongly_connected_components().len();
+		total += start.elapsed();
+	}
+
+	println!(
+		"{name}: {:?} per iteration ({len} components)",
+		total / ITERATIONS
+	)
+}
+
+fn main() {
+	let edges = edges();
+
+	let graph = DiGraph::<(), (), u32>::from_edges(&edges);
+	let csr = Csr::<(), (), _, u32>::from_sorted_edges(&edges).unwrap();
+
+	bench("Graph", &graph);
+	bench("Csr", &csr);
+}
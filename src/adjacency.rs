@@ -0,0 +1,120 @@
+use std::{
+	collections::{hash_map::Entry, HashMap, HashSet},
+	hash::Hash,
+};
+
+use super::Scc;
+
+/// Owned directed graph stored as adjacency sets.
+///
+/// Every endpoint of an edge is added to the graph as a vertex, so that
+/// successors are always vertices of the graph.
+///
+/// ```
+/// use scc_trait::{AdjacencyList, Scc};
+///
+/// let mut graph: AdjacencyList<&str> = [("a", "b"), ("b", "a")].into_iter().collect();
+/// graph.add_edge("b", "c");
+/// graph.add_vertex("d");
+///
+/// assert_eq!(graph.len(), 4);
+/// assert_eq!(graph.strongly_connected_components().len(), 3);
+/// ```
+#[derive(Debug, Clone)]
+pub struct AdjacencyList<V> {
+	successors: HashMap<V, HashSet<V>>,
+}
+
+impl<V> AdjacencyList<V> {
+	/// Creates an empty graph.
+	pub fn new() -> Self {
+		Self {
+			successors: HashMap::new(),
+		}
+	}
+
+	/// Returns the number of vertices.
+	pub fn len(&self) -> usize {
+		self.successors.len()
+	}
+
+	/// Checks if the graph has no vertices.
+	pub fn is_empty(&self) -> bool {
+		self.successors.is_empty()
+	}
+}
+
+impl<V> Default for AdjacencyList<V> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<V: Eq + Hash> AdjacencyList<V> {
+	/// Checks if `v` is a vertex of the graph.
+	pub fn contains_vertex(&self, v: &V) -> bool {
+		self.successors.contains_key(v)
+	}
+
+	/// Checks if there is an edge from `u` to `v`.
+	pub fn contains_edge(&self, u: &V, v: &V) -> bool {
+		self.successors
+			.get(u)
+			.is_some_and(|successors| successors.contains(v))
+	}
+
+	/// Adds a vertex without edges.
+	///
+	/// Returns `false` if the vertex was already in the graph.
+	pub fn add_vertex(&mut self, v: V) -> bool {
+		match self.successors.entry(v) {
+			Entry::Occupied(_) => false,
+			Entry::Vacant(entry) => {
+				entry.insert(HashSet::new());
+				true
+			}
+		}
+	}
+}
+
+impl<V: Clone + Eq + Hash> AdjacencyList<V> {
+	/// Adds an edge from `u` to `v`, adding the vertices if needed.
+	///
+	/// Returns `false` if the edge was already in the graph.
+	pub fn add_edge(&mut self, u: V, v: V) -> bool {
+		self.add_vertex(v.clone());
+		self.successors.entry(u).or_default().insert(v)
+	}
+}
+
+impl<V: Clone + Eq + Hash> FromIterator<(V, V)> for AdjacencyList<V> {
+	fn from_iter<I: IntoIterator<Item = (V, V)>>(iter: I) -> Self {
+		let mut result = Self::new();
+		result.extend(iter);
+		result
+	}
+}
+
+impl<V: Clone + Eq + Hash> Extend<(V, V)> for AdjacencyList<V> {
+	fn extend<I: IntoIterator<Item = (V, V)>>(&mut self, iter: I) {
+		for (u, v) in iter {
+			self.add_edge(u, v);
+		}
+	}
+}
+
+impl<V: Clone + Eq + Hash> Scc for AdjacencyList<V> {
+	type Vertex = V;
+
+	fn vertices(&self) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+		self.successors.keys().cloned()
+	}
+
+	fn successors(&self, v: Self::Vertex) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+		self.successors.get(&v).into_iter().flatten().cloned()
+	}
+
+	fn vertex_count_hint(&self) -> Option<usize> {
+		Some(self.successors.len())
+	}
+}
@@ -0,0 +1,199 @@
+use std::{
+	collections::{HashMap, HashSet},
+	future::Future,
+	hash::Hash,
+};
+
+use super::Components;
+
+/// Graph whose successors are fetched asynchronously, for instance from a
+/// remote service or paged from disk.
+///
+/// This is an asynchronous version of [`Scc`](crate::Scc). The algorithm
+/// itself is synchronous, only fetching the successors of a vertex is
+/// awaited.
+///
+/// ```
+/// # block_on(async {
+/// use scc_trait::AsyncScc;
+///
+/// struct Remote(Vec<Vec<usize>>);
+///
+/// impl AsyncScc for Remote {
+///   type Vertex = usize;
+///
+///   fn vertices(&self) -> impl '_ + IntoIterator<Item = usize> {
+///     0..self.0.len()
+///   }
+///
+///   async fn successors(&self, v: usize) -> impl '_ + IntoIterator<Item = usize> {
+///     self.0[v].iter().copied()
+///   }
+/// }
+///
+/// let components = Remote(vec![vec![1], vec![0], vec![]])
+///   .async_strongly_connected_components()
+///   .await;
+/// assert_eq!(components.len(), 2);
+/// # });
+/// # fn block_on<F: std::future::Future>(f: F) -> F::Output {
+/// #   let waker = std::task::Waker::noop();
+/// #   let mut cx = std::task::Context::from_waker(&waker);
+/// #   let mut f = std::pin::pin!(f);
+/// #   loop {
+/// #     if let std::task::Poll::Ready(output) = f.as_mut().poll(&mut cx) {
+/// #       return output;
+/// #     }
+/// #   }
+/// # }
+/// ```
+pub trait AsyncScc {
+	/// Graph vertex reference type.
+	type Vertex: Clone + Eq + Hash;
+
+	/// Returns an iterator over the vertices of the graph.
+	fn vertices(&self) -> impl '_ + IntoIterator<Item = Self::Vertex>;
+
+	/// Fetches the successors of the given vertex.
+	fn successors(
+		&self,
+		v: Self::Vertex,
+	) -> impl Future<Output = impl '_ + IntoIterator<Item = Self::Vertex>>;
+
+	/// Computes the strongly connected components of the graph.
+	///
+	/// The successors of each vertex are fetched once, and kept in memory
+	/// until the condensation graph is computed.
+	fn async_strongly_connected_components(
+		&self,
+	) -> impl Future<Output = Components<Self::Vertex>> {
+		scc(self)
+	}
+}
+
+/// Vertex data.
+struct Data {
+	index: u32,
+	lowlink: u32,
+	on_stack: bool,
+}
+
+/// Tarjan's algorithm state.
+struct State<V> {
+	/// Fetched successors of each visited vertex.
+	successors: HashMap<V, Vec<V>>,
+
+	/// Visited vertices.
+	data: HashMap<V, Data>,
+
+	/// Tarjan's stack.
+	stack: Vec<V>,
+
+	/// DFS path, with the number of successors already considered for each
+	/// vertex.
+	path: Vec<(V, usize)>,
+
+	/// Components found so far.
+	components: Vec<Vec<V>>,
+}
+
+impl<V: Clone + Eq + Hash> State<V> {
+	async fn visit<G: ?Sized + AsyncScc<Vertex = V>>(&mut self, graph: &G, v: V) {
+		let index = self.data.len() as u32;
+		self.stack.push(v.clone());
+		self.data.insert(
+			v.clone(),
+			Data {
+				index,
+				lowlink: index,
+				on_stack: true,
+			},
+		);
+
+		let successors = graph.successors(v.clone()).await.into_iter().collect();
+		self.successors.insert(v.clone(), successors);
+		self.path.push((v, 0))
+	}
+}
+
+async fn scc<G: ?Sized + AsyncScc>(graph: &G) -> Components<G::Vertex> {
+	let mut state = State {
+		successors: HashMap::new(),
+		data: HashMap::new(),
+		stack: Vec::new(),
+		path: Vec::new(),
+		components: Vec::new(),
+	};
+
+	for root in graph.vertices() {
+		if state.data.contains_key(&root) {
+			continue;
+		}
+
+		state.visit(graph, root).await;
+
+		while let Some((v, k)) = state.path.last().cloned() {
+			match state.successors[&v].get(k).cloned() {
+				Some(w) => {
+					state.path.last_mut().unwrap().1 += 1;
+					match state.data.get(&w) {
+						None => state.visit(graph, w).await,
+						Some(w_data) => {
+							if w_data.on_stack {
+								let w_index = w_data.index;
+								let v_data = state.data.get_mut(&v).unwrap();
+								v_data.lowlink = v_data.lowlink.min(w_index)
+							}
+						}
+					}
+				}
+				None => {
+					state.path.pop();
+
+					let lowlink = state.data[&v].lowlink;
+					if let Some((u, _)) = state.path.last() {
+						let u_data = state.data.get_mut(u).unwrap();
+						u_data.lowlink = u_data.lowlink.min(lowlink)
+					}
+
+					if lowlink == state.data[&v].index {
+						let mut component = Vec::new();
+						loop {
+							let w = state.stack.pop().unwrap();
+							state.data.get_mut(&w).unwrap().on_stack = false;
+							let is_root = w == v;
+							component.push(w);
+
+							if is_root {
+								break;
+							}
+						}
+
+						state.components.push(component)
+					}
+				}
+			}
+		}
+	}
+
+	let vertex_to_component: HashMap<_, _, _> = state
+		.components
+		.iter()
+		.enumerate()
+		.flat_map(|(i, component)| component.iter().map(move |v| (v.clone(), i)))
+		.collect();
+
+	let successors = state
+		.components
+		.iter()
+		.map(|component| {
+			component
+				.iter()
+				.flat_map(|v| &state.successors[v])
+				.map(|w| vertex_to_component[w])
+				.collect::<HashSet<_, _>>()
+		})
+		.collect();
+
+	Components::new(state.components, vertex_to_component, successors)
+}
@@ -0,0 +1,112 @@
+/// Number of bits in a bitset block.
+const BLOCK_BITS: usize = u64::BITS as usize;
+
+/// Transitive closure of a condensation graph.
+///
+/// Created by [`Components::transitive_closure`]. Reachability between
+/// components is stored as one packed bitset row per component, so that
+/// each query takes constant time.
+///
+/// [`Components::transitive_closure`]: crate::Components::transitive_closure
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransitiveClosure {
+	/// Number of components.
+	len: usize,
+
+	/// Number of blocks per row.
+	stride: usize,
+
+	/// Bitset rows, concatenated.
+	blocks: Vec<u64>,
+}
+
+impl TransitiveClosure {
+	/// Creates the closure of `len` components, where every component only
+	/// reaches itself.
+	pub(crate) fn new(len: usize) -> Self {
+		let stride = len.div_ceil(BLOCK_BITS);
+		let mut result = Self {
+			len,
+			stride,
+			blocks: vec![0; len * stride],
+		};
+
+		for i in 0..len {
+			result.blocks[i * stride + i / BLOCK_BITS] |= 1 << (i % BLOCK_BITS)
+		}
+
+		result
+	}
+
+	/// Adds every component reachable from `j` to the row of `i`.
+	pub(crate) fn union_row(&mut self, i: usize, j: usize) {
+		if i != j {
+			for k in 0..self.stride {
+				self.blocks[i * self.stride + k] |= self.blocks[j * self.stride + k]
+			}
+		}
+	}
+
+	/// Returns the number of components.
+	pub fn len(&self) -> usize {
+		self.len
+	}
+
+	/// Checks if there are no components.
+	pub fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+
+	/// Checks if component `j` is reachable from component `i`.
+	///
+	/// Every component is reachable from itself. Returns `false` if either
+	/// index is out of bounds.
+	pub fn reaches(&self, i: usize, j: usize) -> bool {
+		i < self.len
+			&& j < self.len
+			&& self.blocks[i * self.stride + j / BLOCK_BITS] & (1 << (j % BLOCK_BITS)) != 0
+	}
+
+	/// Returns the packed bitset row of component `i`, where bit `j` is set
+	/// if component `j` is reachable from `i`.
+	///
+	/// Bit `j` is bit `j % 64` of block `j / 64`.
+	///
+	/// # Panics
+	///
+	/// Panics if `i` is out of bounds.
+	pub fn row(&self, i: usize) -> &[u64] {
+		assert!(i < self.len, "component index out of bounds");
+		&self.blocks[i * self.stride..(i + 1) * self.stride]
+	}
+
+	/// Returns the first component reachable from `i` whose index is at least
+	/// `j`.
+	pub(crate) fn next_reachable(&self, i: usize, j: usize) -> Option<usize> {
+		let row = self.row(i);
+		let mut k = j / BLOCK_BITS;
+		let mut block = row.get(k)? & (!0 << (j % BLOCK_BITS));
+		loop {
+			if block != 0 {
+				return Some(k * BLOCK_BITS + block.trailing_zeros() as usize);
+			}
+
+			k += 1;
+			block = *row.get(k)?
+		}
+	}
+
+	/// Returns an iterator over the components reachable from `i`, in
+	/// increasing order, including `i` itself.
+	///
+	/// # Panics
+	///
+	/// Panics if `i` is out of bounds.
+	pub fn reachable_from(&self, i: usize) -> impl '_ + Iterator<Item = usize> {
+		self.row(i).iter().enumerate().flat_map(|(k, &block)| {
+			(0..BLOCK_BITS)
+				.filter(move |b| block & (1 << b) != 0)
+				.map(move |b| k * BLOCK_BITS + b)
+		})
+	}
+}
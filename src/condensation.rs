@@ -0,0 +1,57 @@
+use super::{dense, Components, DefaultHashBuilder, DenseScc, Scc};
+
+/// Condensation graph of strongly connected components.
+///
+/// Created by [`Components::condensation`]. Each vertex is a component index,
+/// and the successors of a vertex are the successors of the component,
+/// including itself if the component is cyclic. This allows running any
+/// algorithm written against the [`Scc`] trait on the quotient graph.
+pub struct Condensation<'a, V, S = DefaultHashBuilder> {
+	components: &'a Components<V, S>,
+}
+
+impl<'a, V, S> Condensation<'a, V, S> {
+	/// Creates the condensation graph of the given components.
+	pub fn new(components: &'a Components<V, S>) -> Self {
+		Self { components }
+	}
+
+	/// Returns the underlying components.
+	pub fn components(&self) -> &'a Components<V, S> {
+		self.components
+	}
+}
+
+impl<V, S> Clone for Condensation<'_, V, S> {
+	fn clone(&self) -> Self {
+		*self
+	}
+}
+
+impl<V, S> Copy for Condensation<'_, V, S> {}
+
+impl<V, S> Scc for Condensation<'_, V, S> {
+	type Vertex = usize;
+
+	fn vertices(&self) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+		0..self.components.len()
+	}
+
+	fn successors(&self, v: Self::Vertex) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+		self.components.successors[v].iter().copied()
+	}
+
+	fn vertex_count_hint(&self) -> Option<usize> {
+		Some(self.components.len())
+	}
+
+	fn strongly_connected_components(&self) -> Components<usize> {
+		dense::scc(self)
+	}
+}
+
+impl<V, S> DenseScc for Condensation<'_, V, S> {
+	fn vertex_count(&self) -> usize {
+		self.components.len()
+	}
+}
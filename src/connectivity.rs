@@ -0,0 +1,291 @@
+use std::collections::HashSet;
+
+use super::{Components, Scc};
+
+/// Computes the immediate dominator of each vertex of `graph` reachable from
+/// `root`, using the iterative algorithm of Cooper, Harvey and Kennedy.
+///
+/// The root is its own immediate dominator. Unreachable vertices have no
+/// immediate dominator.
+fn dominators(graph: &[Vec<usize>], root: usize) -> Vec<Option<usize>> {
+	let n = graph.len();
+
+	// Depth-first postorder.
+	let mut postorder = Vec::with_capacity(n);
+	let mut visited = vec![false; n];
+	visited[root] = true;
+	let mut stack = vec![(root, 0)];
+	while let Some((v, k)) = stack.last_mut() {
+		let v = *v;
+		match graph[v].get(*k) {
+			Some(&w) => {
+				*k += 1;
+				if !visited[w] {
+					visited[w] = true;
+					stack.push((w, 0))
+				}
+			}
+			None => {
+				postorder.push(v);
+				stack.pop();
+			}
+		}
+	}
+
+	let mut position = vec![usize::MAX; n];
+	for (p, &v) in postorder.iter().enumerate() {
+		position[v] = p
+	}
+
+	let mut predecessors = vec![Vec::new(); n];
+	for (v, successors) in graph.iter().enumerate() {
+		if visited[v] {
+			for &w in successors {
+				predecessors[w].push(v)
+			}
+		}
+	}
+
+	let intersect = |idom: &[Option<usize>], mut a: usize, mut b: usize| {
+		while a != b {
+			while position[a] < position[b] {
+				a = idom[a].unwrap()
+			}
+
+			while position[b] < position[a] {
+				b = idom[b].unwrap()
+			}
+		}
+
+		a
+	};
+
+	let mut idom = vec![None; n];
+	idom[root] = Some(root);
+
+	let mut changed = true;
+	while changed {
+		changed = false;
+		for &v in postorder.iter().rev() {
+			if v == root {
+				continue;
+			}
+
+			let mut new_idom = None;
+			for &p in &predecessors[v] {
+				if idom[p].is_some() {
+					new_idom = Some(match new_idom {
+						None => p,
+						Some(d) => intersect(&idom, p, d),
+					})
+				}
+			}
+
+			if new_idom != idom[v] {
+				idom[v] = new_idom;
+				changed = true
+			}
+		}
+	}
+
+	idom
+}
+
+/// Returns the reverse of the given graph.
+fn reverse(graph: &[Vec<usize>]) -> Vec<Vec<usize>> {
+	let mut result = vec![Vec::new(); graph.len()];
+	for (v, successors) in graph.iter().enumerate() {
+		for &w in successors {
+			result[w].push(v)
+		}
+	}
+
+	result
+}
+
+/// Component subgraph, using local vertex indices.
+struct Local<V> {
+	/// Component members, by local index.
+	members: Vec<V>,
+
+	/// Successors of each vertex in the component, without self-loops.
+	successors: Vec<Vec<usize>>,
+}
+
+/// Returns the subgraph of each component of `graph` with more than one
+/// vertex.
+fn local_components<G: ?Sized + Scc>(graph: &G) -> Vec<Local<G::Vertex>> {
+	let components = graph.strongly_connected_components();
+	(0..components.len())
+		.filter(|&i| components.component_size(i) > Some(1))
+		.map(|i| {
+			let mut successors = components.induced_subgraph(graph, i).unwrap();
+			for (k, list) in successors.iter_mut().enumerate() {
+				list.retain(|&l| l != k)
+			}
+
+			Local {
+				members: components.get_by_index(i).unwrap().to_vec(),
+				successors,
+			}
+		})
+		.collect()
+}
+
+/// Computes the strong articulation points of the graph.
+///
+/// A strong articulation point is a vertex whose removal splits its strongly
+/// connected component into several components. They are computed using the
+/// algorithm of Italiano, Laura and Santaroni: in each component, a vertex
+/// other than an arbitrary root `r` is a strong articulation point if and only
+/// if it is a non-trivial dominator in the flow graph starting from `r`, or in
+/// its reverse. The root itself is checked separately.
+///
+/// Vertices are returned in no particular order.
+///
+/// ```
+/// use scc_trait::strong_articulation_points;
+///
+/// let graph = vec![vec![1], vec![0, 2], vec![1]];
+/// assert_eq!(strong_articulation_points(&graph), vec![1]);
+/// ```
+pub fn strong_articulation_points<G: ?Sized + Scc>(graph: &G) -> Vec<G::Vertex> {
+	let mut result = Vec::new();
+
+	for Local {
+		members,
+		successors: local,
+	} in local_components(graph)
+	{
+		let n = members.len();
+		let mut selected = vec![false; n];
+
+		for g in [&local, &reverse(&local)] {
+			for (v, d) in dominators(g, 0).into_iter().enumerate() {
+				let d = d.unwrap();
+				if v != 0 && d != 0 {
+					selected[d] = true
+				}
+			}
+		}
+
+		let without_root: Vec<Vec<usize>> = local
+			.iter()
+			.enumerate()
+			.map(|(v, successors)| {
+				if v == 0 {
+					Vec::new()
+				} else {
+					successors.iter().copied().filter(|&w| w != 0).collect()
+				}
+			})
+			.collect();
+
+		selected[0] = without_root
+			.scc_iter()
+			.filter(|component| component != &[0])
+			.nth(1)
+			.is_some();
+
+		result.extend((0..n).filter(|&k| selected[k]).map(|k| members[k].clone()))
+	}
+
+	result
+}
+
+/// Computes the strong bridges of the graph.
+///
+/// A strong bridge is an edge whose removal splits its strongly connected
+/// component into several components. Following Italiano, Laura and
+/// Santaroni, an edge is a strong bridge if and only if it is a bridge of the
+/// flow graph starting from an arbitrary root of the component, or of its
+/// reverse. Bridges are found by splitting each edge `u → v` with a new
+/// vertex `x`: the edge is a bridge if and only if `x` is the immediate
+/// dominator of `v`.
+///
+/// Self-loops are never strong bridges, and parallel edges are considered as
+/// a single edge. Edges are returned in no particular order.
+///
+/// ```
+/// use scc_trait::strong_bridges;
+///
+/// let graph = vec![vec![1], vec![0, 2], vec![1]];
+/// let mut bridges = strong_bridges(&graph);
+/// bridges.sort();
+/// assert_eq!(bridges, [(0, 1), (1, 0), (1, 2), (2, 1)]);
+/// ```
+pub fn strong_bridges<G: ?Sized + Scc>(graph: &G) -> Vec<(G::Vertex, G::Vertex)> {
+	let mut result = Vec::new();
+
+	for Local {
+		members,
+		successors: local,
+	} in local_components(graph)
+	{
+		let n = members.len();
+		let edges: Vec<(usize, usize)> = local
+			.iter()
+			.enumerate()
+			.flat_map(|(u, successors)| successors.iter().map(move |&v| (u, v)))
+			.collect();
+
+		let mut split = vec![Vec::new(); n + edges.len()];
+		for (e, &(u, v)) in edges.iter().enumerate() {
+			split[u].push(n + e);
+			split[n + e].push(v)
+		}
+
+		let forward = dominators(&split, 0);
+		let backward = dominators(&reverse(&split), 0);
+		result.extend(
+			edges
+				.iter()
+				.enumerate()
+				.filter(|&(e, &(u, v))| forward[v] == Some(n + e) || backward[u] == Some(n + e))
+				.map(|(_, &(u, v))| (members[u].clone(), members[v].clone())),
+		)
+	}
+
+	result
+}
+
+/// Computes the 2-edge strongly connected components of the graph.
+///
+/// These are the maximal sets of vertices inducing a subgraph that stays
+/// strongly connected after removing any single edge. Each vertex belongs to
+/// exactly one component, possibly alone. They are computed by repeatedly
+/// removing the strong bridges of the graph until none remain, and taking
+/// the strongly connected components of what is left.
+///
+/// The components are returned without condensation edges: edges of `graph`
+/// may go back and forth between two components, so they do not form a
+/// condensation graph. Only the partition of the vertices is meaningful, and
+/// vertices can be mapped to their component using
+/// [`Components::get`] or [`Components::same_component`].
+///
+/// ```
+/// use scc_trait::two_edge_strongly_connected_components;
+///
+/// let graph = vec![vec![1], vec![0, 2], vec![1, 3], vec![2]];
+/// let components = two_edge_strongly_connected_components(&graph);
+/// assert_eq!(components.len(), 4);
+/// assert!(!components.same_component(&0, &1));
+/// ```
+pub fn two_edge_strongly_connected_components<G: ?Sized + Scc>(graph: &G) -> Components<G::Vertex> {
+	let mut removed = HashSet::new();
+
+	loop {
+		let filtered = graph.filtered(|u, v| !removed.contains(&(u.clone(), v.clone())));
+		let bridges = strong_bridges(&filtered);
+		if bridges.is_empty() {
+			let mut result = Components::empty();
+			for component in filtered.scc_iter() {
+				result.push_component(component);
+			}
+
+			return result;
+		}
+
+		removed.extend(bridges)
+	}
+}
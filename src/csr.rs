@@ -0,0 +1,184 @@
+use std::hash::Hash;
+
+use super::Scc;
+
+/// Unsigned integer type usable as a compact vertex index.
+pub trait IndexType: Copy + Ord + Hash {
+	/// Converts a `usize` into an index.
+	///
+	/// # Panics
+	///
+	/// Panics if `i` does not fit in this type.
+	fn new(i: usize) -> Self;
+
+	/// Converts this index into a `usize`.
+	fn index(self) -> usize;
+}
+
+macro_rules! index_type {
+	($($ty:ty),*) => {
+		$(
+			impl IndexType for $ty {
+				fn new(i: usize) -> Self {
+					<$ty>::try_from(i).expect("index out of range")
+				}
+
+				fn index(self) -> usize {
+					self as usize
+				}
+			}
+		)*
+	};
+}
+
+index_type!(u8, u16, u32, u64, usize);
+
+/// Compressed sparse row graph.
+///
+/// Vertices are the indices `0..n`. The successors of every vertex are stored
+/// contiguously in a single `targets` array, and `offsets[v]..offsets[v + 1]`
+/// is the range of successors of `v` in this array. Iterating over successors
+/// is then a linear scan of memory, which makes this the fastest graph
+/// representation for large static graphs.
+///
+/// ```
+/// use scc_trait::{Csr, Scc};
+///
+/// let graph: Csr = [(0, 1), (1, 0), (1, 2)].into_iter().collect();
+/// assert_eq!(graph.vertex_count(), 3);
+/// assert_eq!(graph.successors_slice(1), [0, 2]);
+/// assert_eq!(graph.strongly_connected_components().len(), 2);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Csr<V = u32> {
+	/// Successor ranges, of length `n + 1`.
+	offsets: Vec<usize>,
+
+	/// Concatenated successor lists.
+	targets: Vec<V>,
+}
+
+impl<V: IndexType> Csr<V> {
+	/// Creates a graph with `vertex_count` vertices from a list of edges.
+	///
+	/// Successors are stored in the order in which edges are listed.
+	///
+	/// # Panics
+	///
+	/// Panics if an edge endpoint is not lower than `vertex_count`.
+	pub fn from_edges(vertex_count: usize, edges: impl IntoIterator<Item = (V, V)>) -> Self {
+		let edges: Vec<(V, V)> = edges.into_iter().collect();
+
+		let mut offsets = vec![0; vertex_count + 1];
+		for &(u, v) in &edges {
+			assert!(
+				u.index() < vertex_count && v.index() < vertex_count,
+				"vertex out of bounds"
+			);
+			offsets[u.index() + 1] += 1
+		}
+
+		for v in 0..vertex_count {
+			offsets[v + 1] += offsets[v]
+		}
+
+		let mut next = offsets.clone();
+		let mut targets = vec![V::new(0); edges.len()];
+		for (u, v) in edges {
+			let slot = &mut next[u.index()];
+			targets[*slot] = v;
+			*slot += 1
+		}
+
+		Self { offsets, targets }
+	}
+
+	/// Creates a graph from its raw `offsets` and `targets` arrays.
+	///
+	/// Returns `None` if the arrays do not describe a valid graph: `offsets`
+	/// must be non-empty, non-decreasing, and end with the length of
+	/// `targets`, and every target must be a vertex.
+	pub fn from_parts(offsets: Vec<usize>, targets: Vec<V>) -> Option<Self> {
+		let vertex_count = offsets.len().checked_sub(1)?;
+		if offsets[vertex_count] != targets.len()
+			|| offsets.windows(2).any(|w| w[0] > w[1])
+			|| targets.iter().any(|v| v.index() >= vertex_count)
+		{
+			return None;
+		}
+
+		Some(Self { offsets, targets })
+	}
+
+	/// Returns the number of vertices.
+	pub fn vertex_count(&self) -> usize {
+		self.offsets.len() - 1
+	}
+
+	/// Returns the successors of `v`, as a slice.
+	///
+	/// # Panics
+	///
+	/// Panics if `v` is out of bounds.
+	pub fn successors_slice(&self, v: V) -> &[V] {
+		let v = v.index();
+		&self.targets[self.offsets[v]..self.offsets[v + 1]]
+	}
+
+	/// Returns the successor ranges, of length `n + 1`.
+	pub fn offsets(&self) -> &[usize] {
+		&self.offsets
+	}
+
+	/// Returns the concatenated successor lists.
+	pub fn targets(&self) -> &[V] {
+		&self.targets
+	}
+}
+
+impl<V> Default for Csr<V> {
+	fn default() -> Self {
+		Self {
+			offsets: vec![0],
+			targets: Vec::new(),
+		}
+	}
+}
+
+/// Collects a list of edges, where the number of vertices is one more than
+/// the highest edge endpoint.
+impl<V: IndexType> FromIterator<(V, V)> for Csr<V> {
+	fn from_iter<I: IntoIterator<Item = (V, V)>>(iter: I) -> Self {
+		let edges: Vec<(V, V)> = iter.into_iter().collect();
+		let vertex_count = edges
+			.iter()
+			.map(|&(u, v)| u.index().max(v.index()) + 1)
+			.max()
+			.unwrap_or(0);
+		Self::from_edges(vertex_count, edges)
+	}
+}
+
+impl<V: IndexType> Scc for Csr<V> {
+	type Vertex = V;
+
+	fn vertices(&self) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+		(0..self.vertex_count()).map(V::new)
+	}
+
+	fn successors(&self, v: Self::Vertex) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+		self.successors_slice(v).iter().copied()
+	}
+
+	fn vertex_count_hint(&self) -> Option<usize> {
+		Some(self.vertex_count())
+	}
+
+	fn edge_count_hint(&self) -> Option<usize> {
+		Some(self.targets.len())
+	}
+
+	fn edge_count(&self) -> usize {
+		self.targets.len()
+	}
+}
@@ -0,0 +1,103 @@
+use std::collections::HashSet;
+
+use super::Scc;
+
+/// Enumerates the elementary cycles of the given graph using Johnson's
+/// algorithm, stopping after `limit` cycles.
+///
+/// Cycles are given as `[v0, ..., vn]` where `v0` is the smallest vertex of
+/// the cycle.
+pub fn johnson(graph: &[Vec<usize>], limit: usize) -> Vec<Vec<usize>> {
+	let n = graph.len();
+	let mut cycles = Vec::new();
+	let mut blocked = vec![false; n];
+	let mut blocked_by: Vec<HashSet<usize>> = vec![HashSet::new(); n];
+
+	for s in 0..n {
+		if cycles.len() >= limit {
+			break;
+		}
+
+		// Restrict the search to the strongly connected component of `s` in
+		// the subgraph induced by the vertices `s..n`.
+		let subgraph: Vec<Vec<usize>> = graph
+			.iter()
+			.enumerate()
+			.map(|(v, successors)| {
+				if v < s {
+					Vec::new()
+				} else {
+					successors.iter().copied().filter(|&w| w >= s).collect()
+				}
+			})
+			.collect();
+
+		let components = subgraph.strongly_connected_components();
+		let component = components.vertex_component_index(&s).unwrap();
+		if !components.is_cyclic(component) {
+			continue;
+		}
+
+		let in_component: Vec<bool> = (0..n)
+			.map(|v| components.vertex_component_index(&v) == Some(component))
+			.collect();
+
+		for &v in components.get_by_index(component).unwrap() {
+			blocked[v] = false;
+			blocked_by[v].clear();
+		}
+
+		// Search path, with the index of the next successor to consider and
+		// whether a cycle was found from each vertex.
+		let mut path = vec![(s, 0, false)];
+		blocked[s] = true;
+
+		while let Some(&mut (v, ref mut next, found)) = path.last_mut() {
+			let successors = &subgraph[v];
+			match successors.get(*next) {
+				Some(&w) => {
+					*next += 1;
+					if !in_component[w] {
+						continue;
+					}
+
+					if w == s {
+						cycles.push(path.iter().map(|(u, _, _)| *u).collect());
+						path.iter_mut().for_each(|(_, _, found)| *found = true);
+						if cycles.len() >= limit {
+							break;
+						}
+					} else if !blocked[w] {
+						blocked[w] = true;
+						path.push((w, 0, false))
+					}
+				}
+				None => {
+					path.pop();
+
+					if found {
+						unblock(v, &mut blocked, &mut blocked_by)
+					} else {
+						for &w in successors {
+							if in_component[w] {
+								blocked_by[w].insert(v);
+							}
+						}
+					}
+				}
+			}
+		}
+	}
+
+	cycles
+}
+
+fn unblock(v: usize, blocked: &mut [bool], blocked_by: &mut [HashSet<usize>]) {
+	let mut stack = vec![v];
+	while let Some(u) = stack.pop() {
+		if blocked[u] {
+			blocked[u] = false;
+			stack.extend(blocked_by[u].drain());
+		}
+	}
+}
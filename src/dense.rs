@@ -0,0 +1,162 @@
+use std::collections::{HashMap, HashSet};
+
+use super::{Components, Scc};
+
+/// Graph whose vertices are the dense range `0..n`.
+///
+/// This allows computing strongly connected components using arrays indexed
+/// by vertex instead of hash maps, which is much faster for graphs with
+/// compact vertex identifiers. The [`Scc`] implementations of every type
+/// implementing this trait in this crate (adjacency lists, matrices,
+/// condensation graphs, and pointers to them) use it automatically.
+///
+/// ```
+/// use scc_trait::DenseScc;
+///
+/// let graph = vec![vec![1], vec![0, 2], vec![]];
+/// let components = graph.strongly_connected_components_dense();
+/// assert_eq!(components.len(), 2);
+/// ```
+pub trait DenseScc: Scc<Vertex = usize> {
+	/// Returns the number of vertices `n`, such that the vertices of the
+	/// graph are exactly `0..n`.
+	fn vertex_count(&self) -> usize;
+
+	/// Computes the strongly connected components of the graph using Tarjan's
+	/// algorithm, with vertex-indexed arrays.
+	///
+	/// The result is the same as
+	/// [`strongly_connected_components`](Scc::strongly_connected_components).
+	///
+	/// # Panics
+	///
+	/// Panics if a successor is not lower than
+	/// [`vertex_count`](Self::vertex_count).
+	fn strongly_connected_components_dense(&self) -> Components<usize> {
+		scc(self)
+	}
+}
+
+/// Unvisited vertex index.
+const UNVISITED: u32 = u32::MAX;
+
+/// Vertex-indexed arrays used by the algorithm, that can be reused between
+/// computations.
+#[derive(Default)]
+pub struct Buffers {
+	/// Index of each vertex, or [`UNVISITED`].
+	index: Vec<u32>,
+
+	/// Lowlink of each vertex.
+	lowlink: Vec<u32>,
+
+	/// Whether each vertex is on Tarjan's stack.
+	on_stack: Vec<bool>,
+
+	/// Tarjan's stack.
+	stack: Vec<usize>,
+}
+
+pub fn scc<G: ?Sized + DenseScc>(graph: &G) -> Components<usize> {
+	scc_with_buffers(graph, &mut Buffers::default())
+}
+
+/// Computes the strongly connected components of `graph`, reusing the
+/// allocations of `buffers`.
+pub fn scc_with_buffers<G: ?Sized + DenseScc>(
+	graph: &G,
+	buffers: &mut Buffers,
+) -> Components<usize> {
+	let n = graph.vertex_count();
+	let Buffers {
+		index,
+		lowlink,
+		on_stack,
+		stack,
+	} = buffers;
+
+	index.clear();
+	index.resize(n, UNVISITED);
+	lowlink.clear();
+	lowlink.resize(n, 0);
+	on_stack.clear();
+	on_stack.resize(n, false);
+	stack.clear();
+
+	let mut component = vec![0; n];
+	let mut next_index = 0;
+	let mut list: Vec<Vec<usize>> = Vec::new();
+
+	for root in 0..n {
+		if index[root] != UNVISITED {
+			continue;
+		}
+
+		index[root] = next_index;
+		lowlink[root] = next_index;
+		next_index += 1;
+		on_stack[root] = true;
+		stack.push(root);
+		let mut path = vec![(root, graph.successors(root).into_iter())];
+
+		while let Some((v, successors)) = path.last_mut() {
+			let v = *v;
+
+			match successors.next() {
+				Some(w) => {
+					if index[w] == UNVISITED {
+						index[w] = next_index;
+						lowlink[w] = next_index;
+						next_index += 1;
+						on_stack[w] = true;
+						stack.push(w);
+						path.push((w, graph.successors(w).into_iter()))
+					} else if on_stack[w] {
+						lowlink[v] = lowlink[v].min(index[w])
+					}
+				}
+				None => {
+					path.pop();
+
+					if let Some(&(u, _)) = path.last() {
+						lowlink[u] = lowlink[u].min(lowlink[v])
+					}
+
+					if lowlink[v] == index[v] {
+						let i = list.len();
+						let mut members = Vec::new();
+						loop {
+							let w = stack.pop().unwrap();
+							on_stack[w] = false;
+							component[w] = i;
+							members.push(w);
+
+							if w == v {
+								break;
+							}
+						}
+
+						list.push(members)
+					}
+				}
+			}
+		}
+	}
+
+	let successors: Vec<HashSet<usize, _>> = list
+		.iter()
+		.map(|members| {
+			members
+				.iter()
+				.flat_map(|&v| graph.successors(v))
+				.map(|w| component[w])
+				.collect()
+		})
+		.collect();
+
+	let vertex_to_component: HashMap<usize, usize, _> = component.into_iter().enumerate().collect();
+	let components = Components::new(list, vertex_to_component, successors);
+
+	debug_assert!(components.is_condensation_acyclic());
+	components
+}
@@ -0,0 +1,71 @@
+use std::hash::Hash;
+
+use super::Scc;
+
+/// Object-safe version of [`Scc`].
+///
+/// [`Scc`] returns `impl Trait` iterators, so it cannot be used as a trait
+/// object. This trait returns boxed iterators instead, and is implemented
+/// for every graph implementing [`Scc`]. In turn, `dyn DynScc` implements
+/// [`Scc`], so it can be used with every algorithm of this crate.
+///
+/// ```
+/// use scc_trait::{DynScc, Scc};
+///
+/// let graphs: Vec<Box<dyn DynScc<Vertex = usize>>> = vec![
+///   Box::new(vec![vec![1], vec![0]]),
+///   Box::new(vec![vec![1], vec![]]),
+/// ];
+///
+/// let counts: Vec<_> = graphs
+///   .iter()
+///   .map(|graph| graph.strongly_connected_components().len())
+///   .collect();
+/// assert_eq!(counts, [1, 2]);
+/// ```
+pub trait DynScc {
+	/// Graph vertex reference type.
+	type Vertex: Clone + Eq + Hash;
+
+	/// Returns an iterator over the vertices of the graph.
+	fn dyn_vertices(&self) -> Box<dyn '_ + Iterator<Item = Self::Vertex>>;
+
+	/// Returns an iterator over the successors of the given vertex.
+	fn dyn_successors(&self, v: Self::Vertex) -> Box<dyn '_ + Iterator<Item = Self::Vertex>>;
+}
+
+impl<G: Scc> DynScc for G {
+	type Vertex = G::Vertex;
+
+	fn dyn_vertices(&self) -> Box<dyn '_ + Iterator<Item = Self::Vertex>> {
+		Box::new(self.vertices().into_iter())
+	}
+
+	fn dyn_successors(&self, v: Self::Vertex) -> Box<dyn '_ + Iterator<Item = Self::Vertex>> {
+		Box::new(self.successors(v).into_iter())
+	}
+}
+
+impl<V: Clone + Eq + Hash> Scc for dyn '_ + DynScc<Vertex = V> {
+	type Vertex = V;
+
+	fn vertices(&self) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+		self.dyn_vertices()
+	}
+
+	fn successors(&self, v: Self::Vertex) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+		self.dyn_successors(v)
+	}
+}
+
+impl<V: Clone + Eq + Hash> Scc for dyn '_ + DynScc<Vertex = V> + Send + Sync {
+	type Vertex = V;
+
+	fn vertices(&self) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+		self.dyn_vertices()
+	}
+
+	fn successors(&self, v: Self::Vertex) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+		self.dyn_successors(v)
+	}
+}
@@ -0,0 +1,487 @@
+use std::{
+	collections::{HashMap, HashSet},
+	hash::Hash,
+};
+
+use super::{Components, Scc};
+
+/// Node of the condensation graph maintained by [`DynamicScc`].
+struct Node<V> {
+	/// Component vertices.
+	members: Vec<V>,
+
+	/// Number of edges to each successor node, excluding itself.
+	successors: HashMap<usize, usize>,
+
+	/// Number of edges from each predecessor node, excluding itself.
+	predecessors: HashMap<usize, usize>,
+}
+
+impl<V> Node<V> {
+	fn new(members: Vec<V>) -> Self {
+		Self {
+			members,
+			successors: HashMap::new(),
+			predecessors: HashMap::new(),
+		}
+	}
+}
+
+/// Graph maintaining its strongly connected components under edge and vertex
+/// insertions and removals.
+///
+/// Inserting an edge merges the components that become strongly connected,
+/// as in [`IncrementalScc`](crate::IncrementalScc). Removing an edge or a
+/// vertex inside a component recomputes the strongly connected components of
+/// this component only.
+///
+/// This type also implements [`Scc`] itself, over the stored graph.
+pub struct DynamicScc<V> {
+	/// Graph edges.
+	successors: HashMap<V, HashSet<V>>,
+
+	/// Reverse graph edges.
+	predecessors: HashMap<V, HashSet<V>>,
+
+	/// Condensation graph nodes, `None` for unused slots.
+	nodes: Vec<Option<Node<V>>>,
+
+	/// Unused node slots.
+	free: Vec<usize>,
+
+	/// Map from vertices to their node.
+	vertex_to_node: HashMap<V, usize>,
+}
+
+impl<V> Default for DynamicScc<V> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<V> DynamicScc<V> {
+	/// Creates an empty graph.
+	pub fn new() -> Self {
+		Self {
+			successors: HashMap::new(),
+			predecessors: HashMap::new(),
+			nodes: Vec::new(),
+			free: Vec::new(),
+			vertex_to_node: HashMap::new(),
+		}
+	}
+
+	/// Returns the number of strongly connected components.
+	pub fn len(&self) -> usize {
+		self.nodes.len() - self.free.len()
+	}
+
+	/// Checks if there are no components, meaning the graph has no vertices.
+	pub fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+
+	fn node(&self, i: usize) -> &Node<V> {
+		self.nodes[i].as_ref().unwrap()
+	}
+
+	fn node_mut(&mut self, i: usize) -> &mut Node<V> {
+		self.nodes[i].as_mut().unwrap()
+	}
+
+	fn add_node(&mut self, node: Node<V>) -> usize {
+		match self.free.pop() {
+			Some(i) => {
+				self.nodes[i] = Some(node);
+				i
+			}
+			None => {
+				self.nodes.push(Some(node));
+				self.nodes.len() - 1
+			}
+		}
+	}
+
+	/// Adds `n` to the number of edges from node `i` to node `j`.
+	fn add_condensation_edges(&mut self, i: usize, j: usize, n: usize) {
+		*self.node_mut(i).successors.entry(j).or_default() += n;
+		*self.node_mut(j).predecessors.entry(i).or_default() += n;
+	}
+
+	/// Removes one edge from node `i` to node `j`.
+	fn remove_condensation_edge(&mut self, i: usize, j: usize) {
+		fn decrement(map: &mut HashMap<usize, usize>, k: usize) {
+			let count = map.get_mut(&k).unwrap();
+			*count -= 1;
+			if *count == 0 {
+				map.remove(&k);
+			}
+		}
+
+		decrement(&mut self.node_mut(i).successors, j);
+		decrement(&mut self.node_mut(j).predecessors, i);
+	}
+}
+
+impl<V: Clone + Eq + Hash> DynamicScc<V> {
+	/// Creates a new structure from the given graph.
+	pub fn from_graph<G: ?Sized + Scc<Vertex = V>>(graph: &G) -> Self {
+		let mut result = Self::new();
+
+		for v in graph.vertices() {
+			result.insert_vertex(v);
+		}
+
+		graph.for_each_edge(|u, v| {
+			result.insert_edge(u, v);
+		});
+
+		result
+	}
+
+	/// Checks if the graph contains the given vertex.
+	pub fn contains_vertex(&self, v: &V) -> bool {
+		self.vertex_to_node.contains_key(v)
+	}
+
+	/// Checks if the graph contains the edge `u → v`.
+	pub fn contains_edge(&self, u: &V, v: &V) -> bool {
+		self.successors.get(u).is_some_and(|s| s.contains(v))
+	}
+
+	/// Inserts a new vertex, in its own component.
+	///
+	/// Returns `false` if the vertex was already present.
+	pub fn insert_vertex(&mut self, v: V) -> bool {
+		if self.vertex_to_node.contains_key(&v) {
+			return false;
+		}
+
+		let i = self.add_node(Node::new(vec![v.clone()]));
+		self.vertex_to_node.insert(v.clone(), i);
+		self.successors.insert(v.clone(), HashSet::new());
+		self.predecessors.insert(v, HashSet::new());
+		true
+	}
+
+	/// Inserts the edge `u → v`, inserting the vertices if necessary.
+	///
+	/// Returns `false` if the edge was already present.
+	pub fn insert_edge(&mut self, u: V, v: V) -> bool {
+		self.insert_vertex(u.clone());
+		self.insert_vertex(v.clone());
+
+		if !self.successors.get_mut(&u).unwrap().insert(v.clone()) {
+			return false;
+		}
+
+		let i = self.vertex_to_node[&u];
+		let j = self.vertex_to_node[&v];
+		self.predecessors.get_mut(&v).unwrap().insert(u);
+
+		if i == j || self.node(i).successors.contains_key(&j) {
+			if i != j {
+				self.add_condensation_edges(i, j, 1)
+			}
+
+			return true;
+		}
+
+		// Every node reachable from `j` that can reach `i` is now part of a
+		// cycle going through the new edge.
+		let mut forward = HashSet::new();
+		forward.insert(j);
+		let mut stack = vec![j];
+		while let Some(k) = stack.pop() {
+			for &l in self.node(k).successors.keys() {
+				if forward.insert(l) {
+					stack.push(l)
+				}
+			}
+		}
+
+		if forward.contains(&i) {
+			let mut cycle = HashSet::new();
+			cycle.insert(i);
+			let mut stack = vec![i];
+			while let Some(k) = stack.pop() {
+				for &l in self.node(k).predecessors.keys() {
+					if forward.contains(&l) && cycle.insert(l) {
+						stack.push(l)
+					}
+				}
+			}
+
+			self.merge(cycle)
+		} else {
+			self.add_condensation_edges(i, j, 1)
+		}
+
+		true
+	}
+
+	/// Merges the given nodes into a single node.
+	fn merge(&mut self, nodes: HashSet<usize>) {
+		let mut members = Vec::new();
+		let mut successors: HashMap<usize, usize> = HashMap::new();
+		let mut predecessors: HashMap<usize, usize> = HashMap::new();
+
+		for &k in &nodes {
+			let node = self.nodes[k].take().unwrap();
+			self.free.push(k);
+			members.extend(node.members);
+
+			for (l, n) in node.successors {
+				if !nodes.contains(&l) {
+					*successors.entry(l).or_default() += n;
+					self.node_mut(l).predecessors.remove(&k);
+				}
+			}
+
+			for (l, n) in node.predecessors {
+				if !nodes.contains(&l) {
+					*predecessors.entry(l).or_default() += n;
+					self.node_mut(l).successors.remove(&k);
+				}
+			}
+		}
+
+		let target = self.add_node(Node::new(members));
+		for v in &self.nodes[target].as_ref().unwrap().members {
+			*self.vertex_to_node.get_mut(v).unwrap() = target;
+		}
+
+		for (l, n) in successors {
+			self.add_condensation_edges(target, l, n)
+		}
+
+		for (l, n) in predecessors {
+			self.add_condensation_edges(l, target, n)
+		}
+	}
+
+	/// Removes the edge `u → v`.
+	///
+	/// Returns `false` if the edge was not present.
+	pub fn remove_edge(&mut self, u: &V, v: &V) -> bool {
+		if !self.successors.get_mut(u).is_some_and(|s| s.remove(v)) {
+			return false;
+		}
+
+		self.predecessors.get_mut(v).unwrap().remove(u);
+
+		let i = self.vertex_to_node[u];
+		let j = self.vertex_to_node[v];
+		if i == j {
+			if u != v {
+				self.split(i)
+			}
+		} else {
+			self.remove_condensation_edge(i, j)
+		}
+
+		true
+	}
+
+	/// Removes the given vertex and all its edges.
+	///
+	/// Returns `false` if the vertex was not present.
+	pub fn remove_vertex(&mut self, v: &V) -> bool {
+		let Some(i) = self.vertex_to_node.remove(v) else {
+			return false;
+		};
+
+		let successors = self.successors.remove(v).unwrap();
+		let predecessors = self.predecessors.remove(v).unwrap();
+
+		for w in successors {
+			if w != *v {
+				self.predecessors.get_mut(&w).unwrap().remove(v);
+				let j = self.vertex_to_node[&w];
+				if j != i {
+					self.remove_condensation_edge(i, j)
+				}
+			}
+		}
+
+		for u in predecessors {
+			if u != *v {
+				self.successors.get_mut(&u).unwrap().remove(v);
+				let j = self.vertex_to_node[&u];
+				if j != i {
+					self.remove_condensation_edge(j, i)
+				}
+			}
+		}
+
+		let node = self.node_mut(i);
+		node.members.retain(|w| w != v);
+
+		if node.members.is_empty() {
+			self.nodes[i] = None;
+			self.free.push(i)
+		} else {
+			self.split(i)
+		}
+
+		true
+	}
+
+	/// Recomputes the strongly connected components of node `i`, after edges
+	/// inside it have been removed.
+	fn split(&mut self, i: usize) {
+		struct Induced<'a, V> {
+			graph: &'a DynamicScc<V>,
+			i: usize,
+		}
+
+		impl<V: Clone + Eq + Hash> Scc for Induced<'_, V> {
+			type Vertex = V;
+
+			fn vertices(&self) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+				self.graph.node(self.i).members.iter().cloned()
+			}
+
+			fn successors(&self, v: Self::Vertex) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+				self.graph.successors[&v]
+					.iter()
+					.filter(|w| self.graph.vertex_to_node[*w] == self.i)
+					.cloned()
+			}
+		}
+
+		let components = Induced { graph: self, i }.strongly_connected_components();
+		if components.len() == 1 {
+			return;
+		}
+
+		// Disconnect the node from the condensation graph.
+		let node = self.nodes[i].take().unwrap();
+		self.free.push(i);
+
+		for &l in node.successors.keys() {
+			self.node_mut(l).predecessors.remove(&i);
+		}
+
+		for &l in node.predecessors.keys() {
+			self.node_mut(l).successors.remove(&i);
+		}
+
+		// Create the new nodes.
+		let mut new_nodes = HashSet::with_capacity(components.len());
+		for component in &components {
+			let k = self.add_node(Node::new(component.to_vec()));
+			for v in component {
+				*self.vertex_to_node.get_mut(v).unwrap() = k;
+			}
+
+			new_nodes.insert(k);
+		}
+
+		// Reconnect the new nodes. Edges between new nodes are counted from
+		// their source only.
+		for &k in &new_nodes {
+			let mut successors: HashMap<usize, usize> = HashMap::new();
+			let mut predecessors: HashMap<usize, usize> = HashMap::new();
+
+			for v in &self.node(k).members {
+				for w in &self.successors[v] {
+					let l = self.vertex_to_node[w];
+					if l != k {
+						*successors.entry(l).or_default() += 1
+					}
+				}
+
+				for u in &self.predecessors[v] {
+					let l = self.vertex_to_node[u];
+					if !new_nodes.contains(&l) {
+						*predecessors.entry(l).or_default() += 1
+					}
+				}
+			}
+
+			for (l, n) in successors {
+				self.add_condensation_edges(k, l, n)
+			}
+
+			for (l, n) in predecessors {
+				self.add_condensation_edges(l, k, n)
+			}
+		}
+	}
+
+	/// Returns the strongly connected component of the given vertex.
+	pub fn get(&self, v: &V) -> Option<&[V]> {
+		let i = *self.vertex_to_node.get(v)?;
+		Some(&self.node(i).members)
+	}
+
+	/// Checks if the two given vertices are in the same strongly connected
+	/// component.
+	pub fn same_component(&self, u: &V, v: &V) -> bool {
+		match (self.vertex_to_node.get(u), self.vertex_to_node.get(v)) {
+			(Some(i), Some(j)) => i == j,
+			_ => false,
+		}
+	}
+
+	/// Returns the current strongly connected components.
+	///
+	/// As for [`Scc::strongly_connected_components`], components are listed
+	/// in reverse topological order.
+	pub fn to_components(&self) -> Components<V> {
+		let mut index = vec![0; self.nodes.len()];
+		let mut list = Vec::with_capacity(self.len());
+		for (i, node) in self.nodes.iter().enumerate() {
+			if let Some(node) = node {
+				index[i] = list.len();
+				list.push(node.members.clone());
+			}
+		}
+
+		let successors = self
+			.nodes
+			.iter()
+			.enumerate()
+			.filter_map(|(i, node)| {
+				let node = node.as_ref()?;
+				let mut successors: HashSet<_, _> =
+					node.successors.keys().map(|&k| index[k]).collect();
+
+				let cyclic = node.members.len() > 1 || {
+					let v = &node.members[0];
+					self.successors[v].contains(v)
+				};
+
+				if cyclic {
+					successors.insert(index[i]);
+				}
+
+				Some(successors)
+			})
+			.collect();
+
+		let vertex_to_component = self
+			.vertex_to_node
+			.iter()
+			.map(|(v, &i)| (v.clone(), index[i]))
+			.collect();
+
+		let components = Components::new(list, vertex_to_component, successors);
+		let mut order = components.topological_order();
+		order.reverse();
+		components.reorder(&order)
+	}
+}
+
+impl<V: Clone + Eq + Hash> Scc for DynamicScc<V> {
+	type Vertex = V;
+
+	fn vertices(&self) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+		self.successors.keys().cloned()
+	}
+
+	fn successors(&self, v: Self::Vertex) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+		self.successors.get(&v).into_iter().flatten().cloned()
+	}
+}
@@ -0,0 +1,61 @@
+use std::hash::Hash;
+
+use super::Components;
+
+/// Graph whose vertices and successors are fetched by operations that may
+/// fail, such as database queries.
+///
+/// This is a fallible version of [`Scc`](crate::Scc), where errors are
+/// propagated to the caller instead of requiring the graph to panic.
+///
+/// ```
+/// use scc_trait::TryScc;
+///
+/// struct Remote(Vec<Option<Vec<usize>>>);
+///
+/// impl TryScc for Remote {
+///   type Vertex = usize;
+///   type Error = String;
+///
+///   fn vertices(&self) -> Result<impl '_ + IntoIterator<Item = usize>, String> {
+///     Ok(0..self.0.len())
+///   }
+///
+///   fn successors(&self, v: usize) -> Result<impl '_ + IntoIterator<Item = usize>, String> {
+///     match &self.0[v] {
+///       Some(successors) => Ok(successors.iter().copied()),
+///       None => Err(format!("vertex {v} is unavailable")),
+///     }
+///   }
+/// }
+///
+/// let graph = Remote(vec![Some(vec![1]), Some(vec![0])]);
+/// assert_eq!(graph.try_strongly_connected_components().unwrap().len(), 1);
+///
+/// let graph = Remote(vec![Some(vec![1]), None]);
+/// assert!(graph.try_strongly_connected_components().is_err());
+/// ```
+pub trait TryScc {
+	/// Graph vertex reference type.
+	type Vertex: Clone + Eq + Hash;
+
+	/// Error type.
+	type Error;
+
+	/// Returns an iterator over the vertices of the graph.
+	fn vertices(&self) -> Result<impl '_ + IntoIterator<Item = Self::Vertex>, Self::Error>;
+
+	/// Returns an iterator over the successors of the given vertex.
+	fn successors(
+		&self,
+		v: Self::Vertex,
+	) -> Result<impl '_ + IntoIterator<Item = Self::Vertex>, Self::Error>;
+
+	/// Computes the strongly connected components of the graph, stopping at
+	/// the first error.
+	///
+	/// The successors of each vertex are fetched only once.
+	fn try_strongly_connected_components(&self) -> Result<Components<Self::Vertex>, Self::Error> {
+		crate::tarjan::try_scc(self)
+	}
+}
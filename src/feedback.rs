@@ -0,0 +1,263 @@
+use std::{
+	collections::{BTreeSet, HashMap},
+	hash::Hash,
+};
+
+use super::{Components, Scc};
+
+/// Component subgraph, using local vertex indices.
+struct Local<'a, V> {
+	members: &'a [V],
+
+	/// Successors of each vertex in the component, without self-loops.
+	successors: Vec<Vec<usize>>,
+
+	/// Predecessors of each vertex in the component, without self-loops.
+	predecessors: Vec<Vec<usize>>,
+
+	/// Vertices with a self-loop.
+	loops: Vec<bool>,
+}
+
+impl<'a, V: Clone + Eq + Hash> Local<'a, V> {
+	fn new<G: ?Sized + Scc<Vertex = V>>(
+		graph: &G,
+		components: &Components<V>,
+		i: usize,
+		members: &'a [V],
+	) -> Self {
+		let index: HashMap<V, usize> = members
+			.iter()
+			.enumerate()
+			.map(|(k, v)| (v.clone(), k))
+			.collect();
+		let n = members.len();
+		let mut successors = vec![Vec::new(); n];
+		let mut predecessors = vec![Vec::new(); n];
+		let mut loops = vec![false; n];
+
+		for (k, v) in members.iter().enumerate() {
+			for w in graph.successors(v.clone()) {
+				if components.vertex_component_index(&w) == Some(i) {
+					let l = index[&w];
+					if l == k {
+						loops[k] = true
+					} else {
+						successors[k].push(l);
+						predecessors[l].push(k)
+					}
+				}
+			}
+		}
+
+		for list in successors.iter_mut().chain(&mut predecessors) {
+			list.sort_unstable();
+			list.dedup()
+		}
+
+		Self {
+			members,
+			successors,
+			predecessors,
+			loops,
+		}
+	}
+
+	/// Computes a vertex ordering with few backward edges using the greedy
+	/// heuristic of Eades, Lin and Smyth.
+	fn ordering(&self) -> Vec<usize> {
+		let n = self.members.len();
+		let mut out_degree: Vec<usize> = self.successors.iter().map(Vec::len).collect();
+		let mut in_degree: Vec<usize> = self.predecessors.iter().map(Vec::len).collect();
+		let delta = |k: usize, out_degree: &[usize], in_degree: &[usize]| {
+			out_degree[k] as isize - in_degree[k] as isize
+		};
+
+		let mut removed = vec![false; n];
+		let mut by_delta: BTreeSet<(isize, usize)> = (0..n)
+			.map(|k| (delta(k, &out_degree, &in_degree), k))
+			.collect();
+		let mut sinks: Vec<usize> = (0..n).filter(|&k| out_degree[k] == 0).collect();
+		let mut sources: Vec<usize> = (0..n).filter(|&k| in_degree[k] == 0).collect();
+
+		let mut head = Vec::with_capacity(n);
+		let mut tail = Vec::new();
+
+		while let Some(&(_, max)) = by_delta.last() {
+			let k = if let Some(k) = sinks.pop() {
+				if removed[k] {
+					continue;
+				}
+
+				tail.push(k);
+				k
+			} else if let Some(k) = sources.pop() {
+				if removed[k] {
+					continue;
+				}
+
+				head.push(k);
+				k
+			} else {
+				head.push(max);
+				max
+			};
+
+			removed[k] = true;
+			by_delta.remove(&(delta(k, &out_degree, &in_degree), k));
+
+			for &p in &self.predecessors[k] {
+				if !removed[p] {
+					by_delta.remove(&(delta(p, &out_degree, &in_degree), p));
+					out_degree[p] -= 1;
+					by_delta.insert((delta(p, &out_degree, &in_degree), p));
+					if out_degree[p] == 0 {
+						sinks.push(p)
+					}
+				}
+			}
+
+			for &s in &self.successors[k] {
+				if !removed[s] {
+					by_delta.remove(&(delta(s, &out_degree, &in_degree), s));
+					in_degree[s] -= 1;
+					by_delta.insert((delta(s, &out_degree, &in_degree), s));
+					if in_degree[s] == 0 {
+						sources.push(s)
+					}
+				}
+			}
+		}
+
+		tail.reverse();
+		head.extend(tail);
+		head
+	}
+}
+
+/// Computes a small set of edges whose removal makes the graph acyclic.
+///
+/// Only edges inside cyclic components can be part of a cycle. For each of
+/// them, vertices are ordered using the greedy heuristic of Eades, Lin and
+/// Smyth, and the edges going backward in this order are selected. Self-loops
+/// are always selected. The result is not guaranteed to be minimal.
+///
+/// ```
+/// use scc_trait::{feedback_arc_set, Scc};
+///
+/// let graph = vec![vec![1], vec![2], vec![0, 3], vec![]];
+/// assert_eq!(feedback_arc_set(&graph).len(), 1);
+/// ```
+pub fn feedback_arc_set<G: ?Sized + Scc>(graph: &G) -> Vec<(G::Vertex, G::Vertex)> {
+	let components = graph.strongly_connected_components();
+	let mut result = Vec::new();
+
+	for (i, members) in components.iter().enumerate() {
+		if !components.is_cyclic(i) {
+			continue;
+		}
+
+		let local = Local::new(graph, &components, i, members);
+
+		let mut position = vec![0; members.len()];
+		for (p, k) in local.ordering().into_iter().enumerate() {
+			position[k] = p
+		}
+
+		for (k, successors) in local.successors.iter().enumerate() {
+			if local.loops[k] {
+				result.push((members[k].clone(), members[k].clone()))
+			}
+
+			for &l in successors {
+				if position[l] < position[k] {
+					result.push((members[k].clone(), members[l].clone()))
+				}
+			}
+		}
+	}
+
+	result
+}
+
+/// Computes a small set of vertices intersecting every cycle of the graph.
+///
+/// Each cyclic component is handled separately: vertices with a self-loop are
+/// selected first, otherwise the vertex maximizing the product of its in and
+/// out degrees inside the component is selected. The strongly connected
+/// components of the remaining vertices are then recomputed, until no cycle
+/// remains. The result is not guaranteed to be minimal.
+///
+/// ```
+/// use scc_trait::{feedback_vertex_set, Scc};
+///
+/// let graph = vec![vec![1, 2], vec![0], vec![0]];
+/// assert_eq!(feedback_vertex_set(&graph), vec![0]);
+/// ```
+pub fn feedback_vertex_set<G: ?Sized + Scc>(graph: &G) -> Vec<G::Vertex> {
+	let components = graph.strongly_connected_components();
+	let mut result = Vec::new();
+
+	for (i, members) in components.iter().enumerate() {
+		if !components.is_cyclic(i) {
+			continue;
+		}
+
+		let local = Local::new(graph, &components, i, members);
+		let mut removed = local.loops.clone();
+		result.extend(
+			(0..members.len())
+				.filter(|&k| removed[k])
+				.map(|k| members[k].clone()),
+		);
+
+		loop {
+			let remaining: Vec<Vec<usize>> = local
+				.successors
+				.iter()
+				.enumerate()
+				.map(|(k, successors)| {
+					if removed[k] {
+						Vec::new()
+					} else {
+						successors
+							.iter()
+							.copied()
+							.filter(|&l| !removed[l])
+							.collect()
+					}
+				})
+				.collect();
+
+			let sub_components = remaining.strongly_connected_components();
+			let mut done = true;
+
+			for (j, sub_members) in sub_components.iter().enumerate() {
+				if !sub_components.is_cyclic(j) {
+					continue;
+				}
+
+				let degree = |list: &[usize]| {
+					list.iter()
+						.filter(|l| sub_components.vertex_component_index(l) == Some(j))
+						.count()
+				};
+
+				let &k = sub_members
+					.iter()
+					.max_by_key(|&&k| degree(&local.predecessors[k]) * degree(&local.successors[k]))
+					.unwrap();
+
+				removed[k] = true;
+				result.push(members[k].clone());
+				done = false
+			}
+
+			if done {
+				break;
+			}
+		}
+	}
+
+	result
+}
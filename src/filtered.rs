@@ -0,0 +1,87 @@
+use super::Scc;
+
+/// Graph adapter hiding some edges of the underlying graph.
+///
+/// Created by [`Scc::filtered`]. The edge `u → v` is kept if and only if the
+/// predicate returns `true` on `(u, v)`.
+pub struct Filtered<'a, G: ?Sized, F> {
+	graph: &'a G,
+	predicate: F,
+}
+
+impl<'a, G: ?Sized, F> Filtered<'a, G, F> {
+	/// Creates a new adapter keeping the edges of `graph` for which
+	/// `predicate` returns `true`.
+	pub fn new(graph: &'a G, predicate: F) -> Self {
+		Self { graph, predicate }
+	}
+}
+
+impl<G, F> Scc for Filtered<'_, G, F>
+where
+	G: ?Sized + Scc,
+	F: Fn(&G::Vertex, &G::Vertex) -> bool,
+{
+	type Vertex = G::Vertex;
+
+	fn vertices(&self) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+		self.graph.vertices()
+	}
+
+	fn successors(&self, v: Self::Vertex) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+		self.graph
+			.successors(v.clone())
+			.into_iter()
+			.filter(move |w| (self.predicate)(&v, w))
+	}
+
+	fn vertex_count_hint(&self) -> Option<usize> {
+		self.graph.vertex_count_hint()
+	}
+}
+
+/// Graph adapter hiding some vertices of the underlying graph.
+///
+/// Created by [`Scc::node_filtered`]. The vertex `v` is kept if and only if
+/// the predicate returns `true` on `v`. This is the subgraph induced by the
+/// kept vertices: edges from or to hidden vertices are hidden as well.
+pub struct NodeFiltered<'a, G: ?Sized, F> {
+	graph: &'a G,
+	predicate: F,
+}
+
+impl<'a, G: ?Sized, F> NodeFiltered<'a, G, F> {
+	/// Creates a new adapter keeping the vertices of `graph` for which
+	/// `predicate` returns `true`.
+	pub fn new(graph: &'a G, predicate: F) -> Self {
+		Self { graph, predicate }
+	}
+}
+
+impl<G, F> Scc for NodeFiltered<'_, G, F>
+where
+	G: ?Sized + Scc,
+	F: Fn(&G::Vertex) -> bool,
+{
+	type Vertex = G::Vertex;
+
+	fn vertices(&self) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+		self.graph
+			.vertices()
+			.into_iter()
+			.filter(|v| (self.predicate)(v))
+	}
+
+	fn successors(&self, v: Self::Vertex) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+		let keep = (self.predicate)(&v);
+		self.graph
+			.successors(v)
+			.into_iter()
+			.filter(move |w| keep && (self.predicate)(w))
+	}
+}
+
+/// Graph adapter hiding some edges of the underlying graph.
+///
+/// Alias of [`Filtered`], whose predicate applies to edges.
+pub type EdgeFiltered<'a, G, F> = Filtered<'a, G, F>;
@@ -0,0 +1,107 @@
+use std::hash::Hash;
+
+use super::Scc;
+
+/// Graph defined by a pair of closures.
+///
+/// The first closure returns the vertices of the graph, and the second one
+/// returns the successors of a vertex. This is handy for one-off
+/// computations on graphs only known implicitly, without defining a new
+/// type implementing [`Scc`].
+///
+/// ```
+/// use scc_trait::{FnGraph, Scc};
+///
+/// let graph = FnGraph::new(|| 0..6u32, |n| [(n + 2) % 6]);
+/// let components = graph.strongly_connected_components();
+/// assert_eq!(components.len(), 2);
+/// ```
+pub struct FnGraph<F, S> {
+	vertices: F,
+	successors: S,
+}
+
+impl<F, S> FnGraph<F, S> {
+	/// Creates a new graph from the given closures.
+	///
+	/// The `vertices` closure is called each time the vertices of the graph
+	/// are enumerated, and `successors` each time the successors of a vertex
+	/// are requested.
+	pub fn new<V, I, J>(vertices: F, successors: S) -> Self
+	where
+		F: Fn() -> I,
+		I: IntoIterator<Item = V>,
+		S: Fn(V) -> J,
+		J: IntoIterator<Item = V>,
+	{
+		Self {
+			vertices,
+			successors,
+		}
+	}
+}
+
+/// Closure returning the vertices of a graph.
+///
+/// The returned iterator type is an associated type, so that it outlives any
+/// borrow of the closure.
+pub trait VerticesFn {
+	/// Vertices iterator.
+	type Vertices: IntoIterator;
+
+	/// Returns the vertices of the graph.
+	fn call(&self) -> Self::Vertices;
+}
+
+impl<F, I> VerticesFn for F
+where
+	F: Fn() -> I,
+	I: IntoIterator,
+{
+	type Vertices = I;
+
+	fn call(&self) -> I {
+		self()
+	}
+}
+
+/// Closure returning the successors of a vertex.
+pub trait SuccessorsFn<V> {
+	/// Successors iterator.
+	type Successors: IntoIterator<Item = V>;
+
+	/// Returns the successors of `v`.
+	fn call(&self, v: V) -> Self::Successors;
+}
+
+impl<V, S, J> SuccessorsFn<V> for S
+where
+	S: Fn(V) -> J,
+	J: IntoIterator<Item = V>,
+{
+	type Successors = J;
+
+	fn call(&self, v: V) -> J {
+		self(v)
+	}
+}
+
+/// Vertex type of a [`FnGraph`].
+type FnVertex<F> = <<F as VerticesFn>::Vertices as IntoIterator>::Item;
+
+impl<F, S> Scc for FnGraph<F, S>
+where
+	F: VerticesFn,
+	FnVertex<F>: Clone + Eq + Hash,
+	S: SuccessorsFn<FnVertex<F>>,
+{
+	type Vertex = FnVertex<F>;
+
+	fn vertices(&self) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+		self.vertices.call()
+	}
+
+	fn successors(&self, v: Self::Vertex) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+		self.successors.call(v)
+	}
+}
@@ -0,0 +1,107 @@
+use std::{collections::HashMap, hash::Hash};
+
+use super::{Components, DefaultHashBuilder, Scc};
+
+// Gabow's path-based SCC algorithm.
+struct Data {
+	preorder: usize,
+	component: Option<usize>,
+}
+
+fn visit<V: Clone + Eq + Hash>(
+	v: V,
+	map: &mut HashMap<V, Data, DefaultHashBuilder>,
+	stack: &mut Vec<V>,
+	boundaries: &mut Vec<V>,
+) {
+	let preorder = map.len();
+	map.insert(
+		v.clone(),
+		Data {
+			preorder,
+			component: None,
+		},
+	);
+	stack.push(v.clone());
+	boundaries.push(v);
+}
+
+pub fn scc<G: ?Sized + Scc>(graph: &G) -> Components<G::Vertex> {
+	let mut map: HashMap<G::Vertex, Data, DefaultHashBuilder> = HashMap::with_capacity_and_hasher(
+		graph.vertex_count_hint().unwrap_or(0),
+		DefaultHashBuilder::default(),
+	);
+
+	// Vertices not yet assigned to a component.
+	let mut stack = Vec::new();
+
+	// Vertices on the DFS path that may be component roots.
+	let mut boundaries: Vec<G::Vertex> = Vec::new();
+
+	let mut components: Vec<Vec<G::Vertex>> = Vec::new();
+
+	for root in graph.vertices() {
+		if map.contains_key(&root) {
+			continue;
+		}
+
+		visit(root.clone(), &mut map, &mut stack, &mut boundaries);
+		let mut path = vec![(root.clone(), graph.successors(root).into_iter())];
+
+		while let Some((v, successors)) = path.last_mut() {
+			let v = v.clone();
+
+			match successors.next() {
+				Some(w) => match map.get(&w) {
+					None => {
+						visit(w.clone(), &mut map, &mut stack, &mut boundaries);
+						path.push((w.clone(), graph.successors(w).into_iter()))
+					}
+					Some(w_data) => {
+						if w_data.component.is_none() {
+							// Contract the cycle going through `w`.
+							let w_preorder = w_data.preorder;
+							while map[boundaries.last().unwrap()].preorder > w_preorder {
+								boundaries.pop();
+							}
+						}
+					}
+				},
+				None => {
+					path.pop();
+
+					if boundaries.last() == Some(&v) {
+						boundaries.pop();
+
+						let i = components.len();
+						let mut component = Vec::new();
+
+						loop {
+							let w = stack.pop().unwrap();
+							map.get_mut(&w).unwrap().component = Some(i);
+							let is_root = w == v;
+							component.push(w);
+
+							if is_root {
+								break;
+							}
+						}
+
+						components.push(component)
+					}
+				}
+			}
+		}
+	}
+
+	let vertex_to_component: HashMap<_, _, _> = map
+		.into_iter()
+		.map(|(v, data)| (v, data.component.unwrap()))
+		.collect();
+
+	let successors = Components::compute_successors(graph, &components, &vertex_to_component);
+	let components = Components::new(components, vertex_to_component, successors);
+
+	debug_assert!(components.is_condensation_acyclic());
+	components
+}
@@ -0,0 +1,70 @@
+use std::hash::Hash;
+
+use super::{Components, Scc};
+
+/// Graph whose vertices are discovered from seed vertices.
+///
+/// This is a relaxed version of [`Scc`] for graphs that cannot enumerate
+/// their vertices up front, such as state spaces generated on the fly.
+/// Components are computed from a set of roots, and the vertex set is
+/// discovered lazily by following successors.
+///
+/// ```
+/// use scc_trait::SccFrom;
+///
+/// /// States `0..10`, where each state steps to `(2 * n) % 10`.
+/// struct Doubling;
+///
+/// impl SccFrom for Doubling {
+///   type Vertex = u32;
+///
+///   fn successors(&self, n: u32) -> impl '_ + IntoIterator<Item = u32> {
+///     [(2 * n) % 10]
+///   }
+/// }
+///
+/// let mut component = Doubling.component_of(2);
+/// component.sort();
+/// assert_eq!(component, [2, 4, 6, 8]);
+/// ```
+pub trait SccFrom {
+	/// Graph vertex reference type.
+	type Vertex: Clone + Eq + Hash;
+
+	/// Returns an iterator over the successors of the given vertex.
+	fn successors(&self, v: Self::Vertex) -> impl '_ + IntoIterator<Item = Self::Vertex>;
+
+	/// Computes the strongly connected components of the subgraph reachable
+	/// from the given `roots`.
+	///
+	/// See [`Scc::strongly_connected_components_from`].
+	fn strongly_connected_components_from(
+		&self,
+		roots: impl IntoIterator<Item = Self::Vertex>,
+	) -> Components<Self::Vertex> {
+		Implicit(self).strongly_connected_components_from(roots)
+	}
+
+	/// Computes the strongly connected component containing `v`.
+	///
+	/// See [`Scc::component_of`].
+	fn component_of(&self, v: Self::Vertex) -> Vec<Self::Vertex> {
+		Implicit(self).component_of(v)
+	}
+}
+
+/// Adapter running the [`Scc`] algorithms on a [`SccFrom`] graph, without
+/// vertices.
+struct Implicit<'a, G: ?Sized>(&'a G);
+
+impl<G: ?Sized + SccFrom> Scc for Implicit<'_, G> {
+	type Vertex = G::Vertex;
+
+	fn vertices(&self) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+		std::iter::empty()
+	}
+
+	fn successors(&self, v: Self::Vertex) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+		self.0.successors(v)
+	}
+}
@@ -0,0 +1,296 @@
+use std::{
+	collections::{HashMap, HashSet},
+	hash::Hash,
+};
+
+use super::{Components, Scc};
+
+/// Node of the condensation graph maintained by [`IncrementalScc`].
+struct Node<V> {
+	/// Component vertices.
+	members: Vec<V>,
+
+	/// Successor nodes, excluding itself.
+	successors: HashSet<usize>,
+
+	/// Predecessor nodes, excluding itself.
+	predecessors: HashSet<usize>,
+
+	/// Whether the component contains a cycle.
+	cyclic: bool,
+}
+
+impl<V> Node<V> {
+	fn new(v: V) -> Self {
+		Self {
+			members: vec![v],
+			successors: HashSet::new(),
+			predecessors: HashSet::new(),
+			cyclic: false,
+		}
+	}
+}
+
+/// Strongly connected components maintained under edge insertions.
+///
+/// Instead of recomputing all the components after each modification of the
+/// graph, edges are inserted one by one, merging the components that become
+/// strongly connected. Inserting an edge only explores the part of the
+/// condensation graph reachable from its target.
+pub struct IncrementalScc<V> {
+	/// Condensation graph nodes, `None` for nodes removed by a merge.
+	nodes: Vec<Option<Node<V>>>,
+
+	/// Unused node slots.
+	free: Vec<usize>,
+
+	/// Map from vertices to their node.
+	vertex_to_node: HashMap<V, usize>,
+
+	/// Number of components.
+	len: usize,
+}
+
+impl<V> Default for IncrementalScc<V> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<V> IncrementalScc<V> {
+	/// Creates an empty graph.
+	pub fn new() -> Self {
+		Self {
+			nodes: Vec::new(),
+			free: Vec::new(),
+			vertex_to_node: HashMap::new(),
+			len: 0,
+		}
+	}
+
+	/// Returns the number of strongly connected components.
+	pub fn len(&self) -> usize {
+		self.len
+	}
+
+	/// Checks if there are no components, meaning the graph has no vertices.
+	pub fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+
+	fn node(&self, i: usize) -> &Node<V> {
+		self.nodes[i].as_ref().unwrap()
+	}
+
+	fn node_mut(&mut self, i: usize) -> &mut Node<V> {
+		self.nodes[i].as_mut().unwrap()
+	}
+}
+
+impl<V: Clone + Eq + Hash> IncrementalScc<V> {
+	/// Creates a new structure from the given graph.
+	pub fn from_graph<G: ?Sized + Scc<Vertex = V>>(graph: &G) -> Self {
+		let mut result = Self::new();
+
+		for v in graph.vertices() {
+			result.insert_vertex(v);
+		}
+
+		graph.for_each_edge(|u, v| {
+			result.insert_edge(u, v);
+		});
+
+		result
+	}
+
+	/// Inserts a new vertex, in its own component.
+	///
+	/// Returns `false` if the vertex was already present.
+	pub fn insert_vertex(&mut self, v: V) -> bool {
+		self.node_index(v).1
+	}
+
+	/// Returns the node of the given vertex, inserting it if necessary.
+	fn node_index(&mut self, v: V) -> (usize, bool) {
+		match self.vertex_to_node.get(&v) {
+			Some(&i) => (i, false),
+			None => {
+				let node = Some(Node::new(v.clone()));
+				let i = match self.free.pop() {
+					Some(i) => {
+						self.nodes[i] = node;
+						i
+					}
+					None => {
+						self.nodes.push(node);
+						self.nodes.len() - 1
+					}
+				};
+
+				self.vertex_to_node.insert(v, i);
+				self.len += 1;
+				(i, true)
+			}
+		}
+	}
+
+	/// Inserts the edge `u → v`, inserting the vertices if necessary.
+	///
+	/// Returns `true` if some components were merged.
+	pub fn insert_edge(&mut self, u: V, v: V) -> bool {
+		let i = self.node_index(u).0;
+		let j = self.node_index(v).0;
+
+		if i == j {
+			self.node_mut(i).cyclic = true;
+			return false;
+		}
+
+		if self.node(i).successors.contains(&j) {
+			return false;
+		}
+
+		// Every node reachable from `j` that can reach `i` is now part of a
+		// cycle going through the new edge.
+		let mut forward = HashSet::new();
+		forward.insert(j);
+		let mut stack = vec![j];
+		while let Some(k) = stack.pop() {
+			for &l in &self.node(k).successors {
+				if forward.insert(l) {
+					stack.push(l)
+				}
+			}
+		}
+
+		if forward.contains(&i) {
+			let mut cycle = HashSet::new();
+			cycle.insert(i);
+			let mut stack = vec![i];
+			while let Some(k) = stack.pop() {
+				for &l in &self.node(k).predecessors {
+					if forward.contains(&l) && cycle.insert(l) {
+						stack.push(l)
+					}
+				}
+			}
+
+			self.merge(cycle);
+			true
+		} else {
+			self.node_mut(i).successors.insert(j);
+			self.node_mut(j).predecessors.insert(i);
+			false
+		}
+	}
+
+	/// Merges the given nodes into a single cyclic node.
+	fn merge(&mut self, nodes: HashSet<usize>) {
+		// Keep the largest node, to minimize the number of relabeled vertices.
+		let target = *nodes
+			.iter()
+			.max_by_key(|&&k| self.node(k).members.len())
+			.unwrap();
+
+		let mut successors = HashSet::new();
+		let mut predecessors = HashSet::new();
+
+		for &k in &nodes {
+			if k != target {
+				let node = self.nodes[k].take().unwrap();
+				self.free.push(k);
+				self.len -= 1;
+
+				for v in &node.members {
+					*self.vertex_to_node.get_mut(v).unwrap() = target;
+				}
+
+				self.node_mut(target).members.extend(node.members);
+				successors.extend(node.successors);
+				predecessors.extend(node.predecessors);
+			}
+		}
+
+		let target_node = self.node_mut(target);
+		target_node.cyclic = true;
+		successors.extend(target_node.successors.drain());
+		predecessors.extend(target_node.predecessors.drain());
+
+		// Redirect the edges of the merged nodes.
+		successors.retain(|k| !nodes.contains(k));
+		predecessors.retain(|k| !nodes.contains(k));
+
+		for &k in &successors {
+			let node = self.node_mut(k);
+			node.predecessors.retain(|l| !nodes.contains(l));
+			node.predecessors.insert(target);
+		}
+
+		for &k in &predecessors {
+			let node = self.node_mut(k);
+			node.successors.retain(|l| !nodes.contains(l));
+			node.successors.insert(target);
+		}
+
+		let target_node = self.node_mut(target);
+		target_node.successors = successors;
+		target_node.predecessors = predecessors;
+	}
+
+	/// Returns the strongly connected component of the given vertex.
+	pub fn get(&self, v: &V) -> Option<&[V]> {
+		let i = *self.vertex_to_node.get(v)?;
+		Some(&self.node(i).members)
+	}
+
+	/// Checks if the two given vertices are in the same strongly connected
+	/// component.
+	pub fn same_component(&self, u: &V, v: &V) -> bool {
+		match (self.vertex_to_node.get(u), self.vertex_to_node.get(v)) {
+			(Some(i), Some(j)) => i == j,
+			_ => false,
+		}
+	}
+
+	/// Returns the current strongly connected components.
+	///
+	/// As for [`Scc::strongly_connected_components`], components are listed
+	/// in reverse topological order.
+	pub fn to_components(&self) -> Components<V> {
+		let mut index = vec![0; self.nodes.len()];
+		let mut list = Vec::with_capacity(self.len);
+		for (i, node) in self.nodes.iter().enumerate() {
+			if let Some(node) = node {
+				index[i] = list.len();
+				list.push(node.members.clone());
+			}
+		}
+
+		let successors = self
+			.nodes
+			.iter()
+			.enumerate()
+			.filter_map(|(i, node)| {
+				let node = node.as_ref()?;
+				let mut successors: HashSet<_, _> =
+					node.successors.iter().map(|&k| index[k]).collect();
+				if node.cyclic {
+					successors.insert(index[i]);
+				}
+
+				Some(successors)
+			})
+			.collect();
+
+		let vertex_to_component = self
+			.vertex_to_node
+			.iter()
+			.map(|(v, &i)| (v.clone(), index[i]))
+			.collect();
+
+		let components = Components::new(list, vertex_to_component, successors);
+		let mut order = components.topological_order();
+		order.reverse();
+		components.reorder(&order)
+	}
+}
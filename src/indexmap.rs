@@ -0,0 +1,49 @@
+//! [`Scc`] implementation for [`indexmap`] maps.
+use std::hash::{BuildHasher, Hash};
+
+use ::indexmap::IndexMap;
+
+use crate::Scc;
+
+/// Insertion-ordered adjacency map, where `C` is any successor container
+/// (`IndexSet`, `Vec`, etc.).
+///
+/// Vertices only appearing as successors, without an entry of their own, are
+/// treated as vertices without successors.
+///
+/// Vertices are enumerated in insertion order, so the computed components,
+/// and the order of vertices in each component, only depend on the order in
+/// which the map and the successor containers were built.
+///
+/// ```
+/// use indexmap::{IndexMap, IndexSet};
+/// use scc_trait::Scc;
+///
+/// let graph: IndexMap<u32, IndexSet<u32>> = IndexMap::from([
+///   (3, IndexSet::from([1])),
+///   (1, IndexSet::from([2])),
+///   (2, IndexSet::from([1])),
+/// ]);
+///
+/// let components = graph.strongly_connected_components();
+/// let list: Vec<&[u32]> = components.iter().collect();
+/// assert_eq!(list, [&[2, 1][..], &[3]]);
+/// ```
+impl<T: Clone + Eq + Hash, C, S: BuildHasher> Scc for IndexMap<T, C, S>
+where
+	for<'a> &'a C: IntoIterator<Item = &'a T>,
+{
+	type Vertex = T;
+
+	fn vertices(&self) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+		self.keys().cloned()
+	}
+
+	fn successors(&self, v: Self::Vertex) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+		self.get(&v).into_iter().flatten().cloned()
+	}
+
+	fn vertex_count_hint(&self) -> Option<usize> {
+		Some(self.len())
+	}
+}
@@ -0,0 +1,79 @@
+use std::{collections::HashSet, fmt::Write};
+
+use serde::Serialize;
+
+use super::Components;
+
+impl<V: Serialize, S> Components<V, S> {
+	/// Exports the components reachable from `root` as a nested JSON tree.
+	///
+	/// Each component is represented as an object
+	/// `{ "index": i, "vertices": [...], "successors": [...] }`, where
+	/// `successors` contains the nested representation of each successor
+	/// component (excluding itself), by increasing index. Since the
+	/// condensation is a DAG and not a tree, components already expanded
+	/// elsewhere in the tree are represented as a back-reference
+	/// `{ "ref": i }` instead.
+	///
+	/// The tree is written using an explicit stack, so that deep condensation
+	/// graphs do not overflow the call stack.
+	///
+	/// Returns `None` if `root` is out of bounds.
+	pub fn to_nested_json(&self, root: usize) -> Option<String> {
+		if root >= self.list.len() {
+			return None;
+		}
+
+		let mut result = String::new();
+		let mut visited = HashSet::new();
+		visited.insert(root);
+
+		// Components being written, with their remaining successors.
+		let mut stack = vec![self.open_nested_json(root, &mut result)];
+		while let Some((i, successors)) = stack.last_mut() {
+			match successors.next() {
+				Some(j) => {
+					if !result.ends_with('[') {
+						result.push(',')
+					}
+
+					if visited.insert(j) {
+						stack.push(self.open_nested_json(j, &mut result))
+					} else {
+						write!(result, "{{\"ref\":{j}}}").unwrap()
+					}
+				}
+				None => {
+					let vertices = serde_json::to_string(&self.list[*i]).unwrap();
+					write!(result, "],\"vertices\":{vertices}}}").unwrap();
+					stack.pop();
+				}
+			}
+		}
+
+		Some(result)
+	}
+
+	/// Returns the successors of component `i` (excluding itself), by
+	/// increasing index.
+	fn nested_json_successors(&self, i: usize) -> Vec<usize> {
+		let mut successors: Vec<_> = self.successors[i]
+			.iter()
+			.copied()
+			.filter(|&j| j != i)
+			.collect();
+		successors.sort_unstable();
+		successors
+	}
+
+	/// Writes the beginning of the representation of component `i`, up to its
+	/// successors list.
+	fn open_nested_json(
+		&self,
+		i: usize,
+		result: &mut String,
+	) -> (usize, std::vec::IntoIter<usize>) {
+		write!(result, "{{\"index\":{i},\"successors\":[").unwrap();
+		(i, self.nested_json_successors(i).into_iter())
+	}
+}
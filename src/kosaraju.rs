@@ -0,0 +1,73 @@
+use std::collections::{HashMap, HashSet};
+
+use super::{Components, DefaultHashBuilder, SccBidirectional};
+
+// Kosaraju's SCC algorithm.
+pub fn scc<G: ?Sized + SccBidirectional>(graph: &G) -> Components<G::Vertex> {
+	// First pass: order vertices by DFS finish time on the graph.
+	let mut visited = HashSet::new();
+	let mut finished = Vec::new();
+
+	for root in graph.vertices() {
+		if !visited.insert(root.clone()) {
+			continue;
+		}
+
+		let mut path = vec![(root.clone(), graph.successors(root).into_iter())];
+		while let Some((v, successors)) = path.last_mut() {
+			match successors.next() {
+				Some(w) => {
+					if visited.insert(w.clone()) {
+						path.push((w.clone(), graph.successors(w).into_iter()))
+					}
+				}
+				None => {
+					finished.push(v.clone());
+					path.pop();
+				}
+			}
+		}
+	}
+
+	// Second pass: visit the transposed graph by decreasing finish time. Each
+	// search tree is a strongly connected component.
+	let mut vertex_to_component: HashMap<G::Vertex, usize, DefaultHashBuilder> = HashMap::default();
+	let mut components = Vec::new();
+
+	for root in finished.into_iter().rev() {
+		if vertex_to_component.contains_key(&root) {
+			continue;
+		}
+
+		let i = components.len();
+		vertex_to_component.insert(root.clone(), i);
+		let mut component = Vec::new();
+		let mut stack = vec![root];
+
+		while let Some(v) = stack.pop() {
+			component.push(v.clone());
+			for u in graph.predecessors(v) {
+				if visited.contains(&u) && !vertex_to_component.contains_key(&u) {
+					vertex_to_component.insert(u.clone(), i);
+					stack.push(u)
+				}
+			}
+		}
+
+		components.push(component)
+	}
+
+	// Components are found in topological order. Reverse them to follow the
+	// same convention as Tarjan's algorithm.
+	components.reverse();
+	let len = components.len();
+	for i in vertex_to_component.values_mut() {
+		*i = len - 1 - *i
+	}
+
+	let successors = Components::compute_successors(graph, &components, &vertex_to_component);
+	let components = Components::new(components, vertex_to_component, successors);
+
+	debug_assert!(components.is_condensation_acyclic());
+	components
+}
@@ -0,0 +1,54 @@
+use std::collections::HashMap;
+
+/// Human-readable component labels.
+///
+/// Side table associating a label to each component of a [`Components`]
+/// value, created using [`Components::with_labels`].
+///
+/// [`Components`]: crate::Components
+/// [`Components::with_labels`]: crate::Components::with_labels
+#[derive(Debug, Clone)]
+pub struct ComponentLabels {
+	/// Label of each component.
+	labels: Vec<String>,
+
+	/// Map from labels to the first component with this label.
+	by_label: HashMap<String, usize>,
+}
+
+impl ComponentLabels {
+	/// Creates a new side table from the label of each component.
+	pub fn new(labels: Vec<String>) -> Self {
+		let mut by_label = HashMap::new();
+		for (i, label) in labels.iter().enumerate() {
+			by_label.entry(label.clone()).or_insert(i);
+		}
+
+		Self { labels, by_label }
+	}
+
+	/// Returns the number of labels, which is the number of components.
+	pub fn len(&self) -> usize {
+		self.labels.len()
+	}
+
+	/// Checks if there are no labels.
+	pub fn is_empty(&self) -> bool {
+		self.labels.is_empty()
+	}
+
+	/// Returns the label of the component with index `i`.
+	pub fn get(&self, i: usize) -> Option<&str> {
+		self.labels.get(i).map(String::as_str)
+	}
+
+	/// Returns the index of the first component with the given label.
+	pub fn index_of(&self, label: &str) -> Option<usize> {
+		self.by_label.get(label).copied()
+	}
+
+	/// Returns an iterator over the component labels, by component index.
+	pub fn iter(&self) -> impl '_ + DoubleEndedIterator<Item = &str> {
+		self.labels.iter().map(String::as_str)
+	}
+}
@@ -9,6 +9,8 @@
 //! First, implement the `Scc` trait on your custom graph type, providing
 //! enough information about the graph structure:
 //! ```
+//! # use std::collections::{HashMap, HashSet};
+//! # use scc_trait::Scc;
 //! # type Vertex = usize;
 //! struct MyGraphType {
 //!   vertices: Vec<Vertex>,
@@ -29,21 +31,21 @@
 //! ```
 //!
 //! This trait is also implemented for a few default types like
-//! `Vec<HashSet<usize>>` and `HashMap<T, HashSet<T>>`. It provides the
+//! `Vec<Vec<usize>>` and `HashMap<T, HashSet<T>>`. It provides the
 //! [`strongly_connected_components`](Scc::strongly_connected_components) method
 //! returning the strongly connected [`Components`] of the graph. This type
 //! allows you to iterate through the components, get successors of a component,
 //! order the components by depth, etc.
 //!
 //! ```
-//! # let graph: Vec<HashSet<usize>> = Vec::new();
+//! # let graph: Vec<Vec<usize>> = Vec::new();
 //! use scc_trait::Scc;
 //!
 //! // Compute the strongly connected components.
 //! let components = graph.strongly_connected_components();
 //!
 //! // Print vertices grouped by component.
-//! for component in components {
+//! for component in &components {
 //!   for vertex in component {
 //!     println!("{vertex}");
 //!   }
@@ -56,150 +58,2417 @@
 //! }
 //! ```
 use std::{
-	collections::{HashMap, HashSet},
-	hash::Hash,
+	collections::{BTreeMap, BinaryHeap, HashMap, HashSet, VecDeque},
+	hash::{BuildHasher, Hash},
+	ops::Add,
+	rc::Rc,
+	sync::{Arc, OnceLock},
 };
 
+mod adjacency;
+mod asynchronous;
+mod closure;
+mod compact;
+mod condensation;
+mod connectivity;
+mod csr;
+mod cycles;
+mod dense;
+mod dyn_scc;
+mod dynamic;
+mod fallible;
+mod feedback;
+mod filtered;
+mod fn_graph;
+mod gabow;
+mod implicit;
+mod incremental;
+mod kosaraju;
+mod labels;
+mod matrix;
+mod pearce;
+mod resumable;
+mod reversed;
 mod tarjan;
+mod topological_sort;
+mod two_sat;
+mod union;
+mod union_find;
+mod visitor;
+
+#[cfg(feature = "petgraph")]
+mod petgraph;
+
+#[cfg(feature = "indexmap")]
+mod indexmap;
+
+#[cfg(feature = "slotmap")]
+mod slotmap;
+
+#[cfg(feature = "serde")]
+mod json;
+
+#[cfg(feature = "rayon")]
+mod parallel;
+
+#[cfg(feature = "memmap")]
+mod mmap;
+
+#[cfg(feature = "memmap")]
+pub use mmap::MmapCsrGraph;
+
+#[cfg(feature = "fxhash")]
+pub use rustc_hash::FxBuildHasher;
+
+/// Default hasher of the component maps and of the vertex maps internal to
+/// the algorithms.
+///
+/// This is the standard library's [`RandomState`] or, with the `fxhash`
+/// feature enabled, the much faster (but not HashDoS resistant) Fx hasher.
+///
+/// [`RandomState`]: std::collections::hash_map::RandomState
+#[cfg(feature = "fxhash")]
+pub type DefaultHashBuilder = rustc_hash::FxBuildHasher;
+
+/// Default hasher of the component maps and of the vertex maps internal to
+/// the algorithms.
+///
+/// This is the standard library's [`RandomState`] or, with the `fxhash`
+/// feature enabled, the much faster (but not HashDoS resistant) Fx hasher.
+///
+/// [`RandomState`]: std::collections::hash_map::RandomState
+#[cfg(not(feature = "fxhash"))]
+pub type DefaultHashBuilder = std::collections::hash_map::RandomState;
+
+pub use adjacency::AdjacencyList;
+pub use asynchronous::AsyncScc;
+pub use closure::TransitiveClosure;
+pub use compact::CompactComponents;
+pub use condensation::Condensation;
+pub use connectivity::*;
+pub use csr::{Csr, IndexType};
+pub use dense::DenseScc;
+pub use dyn_scc::DynScc;
+pub use dynamic::DynamicScc;
+pub use fallible::TryScc;
+pub use feedback::*;
+pub use filtered::{EdgeFiltered, Filtered, NodeFiltered};
+pub use fn_graph::{FnGraph, SuccessorsFn, VerticesFn};
+pub use implicit::SccFrom;
+pub use incremental::IncrementalScc;
+pub use labels::ComponentLabels;
+pub use matrix::{AdjacencyMatrix, BitMatrix};
+pub use resumable::SccProgress;
+pub use reversed::Reversed;
+pub use tarjan::SccWorkspace;
+pub use two_sat::TwoSat;
+pub use union::*;
+use union_find::UnionFind;
+pub use visitor::*;
 
 /// Graph on which strongly connected components can be computed.
+///
+/// This trait is implemented for references and smart pointers (`Box`, `Rc`,
+/// `Arc`) to any graph implementing it.
+///
+/// ```
+/// use std::rc::Rc;
+/// use scc_trait::Scc;
+///
+/// fn count<G: Scc>(graph: G) -> usize {
+///   graph.strongly_connected_components().len()
+/// }
+///
+/// let graph = vec![vec![1], vec![0], vec![]];
+/// assert_eq!(count(&graph), 2);
+/// assert_eq!(count(Rc::new(graph)), 2);
+/// ```
 pub trait Scc {
 	/// Graph vertex reference type.
-	type Vertex: Copy + Eq + Hash;
+	///
+	/// Vertices are cloned as needed by the algorithms, so this should be
+	/// cheap to clone, such as an index, a reference or an `Rc`.
+	///
+	/// ```
+	/// use std::collections::HashMap;
+	/// use scc_trait::Scc;
+	///
+	/// let graph: HashMap<String, Vec<String>> = HashMap::from([
+	///     ("a".to_string(), vec!["b".to_string()]),
+	///     ("b".to_string(), vec!["a".to_string()]),
+	/// ]);
+	///
+	/// let components = graph.strongly_connected_components();
+	/// assert_eq!(components.len(), 1);
+	/// ```
+	type Vertex: Clone + Eq + Hash;
 
 	/// Returns an iterator over the vertices of the graph.
+	///
+	/// Vertices yielded more than once are tolerated, and do not affect the
+	/// computed components.
 	fn vertices(&self) -> impl '_ + IntoIterator<Item = Self::Vertex>;
 
 	/// Returns an iterator over the successors of the given vertex.
 	fn successors(&self, v: Self::Vertex) -> impl '_ + IntoIterator<Item = Self::Vertex>;
 
+	/// Returns an estimate of the number of vertices of the graph, if known
+	/// in advance.
+	///
+	/// This is only used to pre-allocate the data structures of the
+	/// algorithms, and does not need to be exact.
+	fn vertex_count_hint(&self) -> Option<usize> {
+		None
+	}
+
+	/// Returns an estimate of the number of edges of the graph, if known in
+	/// advance.
+	///
+	/// Like [`vertex_count_hint`](Self::vertex_count_hint), this is only used
+	/// for pre-allocation, and does not need to be exact.
+	fn edge_count_hint(&self) -> Option<usize> {
+		None
+	}
+
 	/// Computes the strongly connected components of the graph.
+	///
+	/// Components are indexed in the order they are found by Tarjan's
+	/// algorithm, which is a reverse topological order: for every edge
+	/// `i → j` between distinct components, `j < i`. Use
+	/// [`Components::topological_order`] to get an order that does not
+	/// depend on the algorithm.
 	fn strongly_connected_components(&self) -> Components<Self::Vertex> {
 		tarjan::scc(self)
 	}
+
+	/// Computes the strongly connected components of the graph, using the
+	/// given hasher for every internal vertex map, including the one stored
+	/// in the returned components.
+	///
+	/// This allows using a faster (or deterministic) hasher than the
+	/// standard library's default one. With the `fxhash` feature enabled,
+	/// `FxBuildHasher` is re-exported by this crate for this purpose.
+	///
+	/// ```
+	/// use std::{collections::hash_map::DefaultHasher, hash::BuildHasherDefault};
+	/// use scc_trait::Scc;
+	///
+	/// let graph = vec![vec![1], vec![0], vec![]];
+	/// let components = graph
+	///   .strongly_connected_components_with_hasher(BuildHasherDefault::<DefaultHasher>::default());
+	/// assert!(components.same_component(&0, &1));
+	/// ```
+	fn strongly_connected_components_with_hasher<S: BuildHasher + Clone>(
+		&self,
+		hasher: S,
+	) -> Components<Self::Vertex, S> {
+		tarjan::scc_with_hasher(self, hasher)
+	}
+
+	/// Computes the strongly connected components of the graph, using the
+	/// narrow index type `Ix` for component indices.
+	///
+	/// See [`CompactComponents`].
+	///
+	/// # Panics
+	///
+	/// Panics if the number of components does not fit in `Ix`.
+	fn strongly_connected_components_compact<Ix: IndexType>(
+		&self,
+	) -> CompactComponents<Self::Vertex, Ix> {
+		tarjan::scc_compact(self)
+	}
+
+	/// Computes the strongly connected components of the subgraph reachable
+	/// from the given `roots`.
+	///
+	/// The graph [`vertices`](Self::vertices) are not enumerated, so vertices
+	/// unreachable from the roots are never visited and have no component.
+	///
+	/// ```
+	/// use scc_trait::Scc;
+	///
+	/// let graph = vec![vec![1], vec![0], vec![0]];
+	/// let components = graph.strongly_connected_components_from([0]);
+	/// assert_eq!(components.len(), 1);
+	/// assert_eq!(components.vertex_component_index(&2), None);
+	/// ```
+	fn strongly_connected_components_from(
+		&self,
+		roots: impl IntoIterator<Item = Self::Vertex>,
+	) -> Components<Self::Vertex> {
+		tarjan::scc_from(self, roots)
+	}
+
+	/// Returns a lazy iterator over the strongly connected components of the
+	/// graph.
+	///
+	/// Components are yielded one by one as Tarjan's algorithm finds them, in
+	/// the same order as [`Self::strongly_connected_components`], without
+	/// computing the condensation graph. The search stops as soon as the
+	/// iterator is dropped.
+	///
+	/// ```
+	/// use scc_trait::Scc;
+	///
+	/// let graph = vec![vec![1], vec![0], vec![2]];
+	/// let first_cyclic = graph.scc_iter().find(|component| component.len() > 1);
+	/// assert_eq!(first_cyclic.map(|c| c.len()), Some(2));
+	/// ```
+	fn scc_iter(&self) -> impl '_ + Iterator<Item = Vec<Self::Vertex>> {
+		tarjan::iter(self)
+	}
+
+	/// Computes the strongly connected components of the graph using
+	/// [Pearce's memory-efficient variant][1] of Tarjan's algorithm.
+	///
+	/// This only stores a single integer per vertex during the computation,
+	/// instead of the index, lowlink, stack flag and component index used by
+	/// [`strongly_connected_components`](Self::strongly_connected_components).
+	///
+	/// [1]: <https://homepages.ecs.vuw.ac.nz/~djp/files/IPL15-preprint.pdf>
+	fn strongly_connected_components_pearce(&self) -> Components<Self::Vertex> {
+		pearce::scc(self)
+	}
+
+	/// Computes the strongly connected components of the graph using
+	/// [Gabow's path-based algorithm][1].
+	///
+	/// The result is the same as
+	/// [`strongly_connected_components`](Self::strongly_connected_components),
+	/// this is provided to compare the performances of both algorithms.
+	///
+	/// [1]: <https://en.wikipedia.org/wiki/Path-based_strong_component_algorithm>
+	fn strongly_connected_components_gabow(&self) -> Components<Self::Vertex> {
+		gabow::scc(self)
+	}
+
+	/// Computes the strongly connected components of the graph in parallel.
+	///
+	/// This uses the Forward-Backward algorithm with trimming, where the
+	/// graph is recursively split into independent sub-problems processed by
+	/// the [`rayon`] thread pool. The graph is first copied into a dense
+	/// representation, including its reverse edges.
+	#[cfg(feature = "rayon")]
+	fn par_strongly_connected_components(&self) -> Components<Self::Vertex>
+	where
+		Self: Sync,
+		Self::Vertex: Send + Sync,
+	{
+		parallel::scc(self)
+	}
+
+	/// Returns the set of vertices reachable from `from`, including `from`
+	/// itself.
+	fn reachable_set(&self, from: Self::Vertex) -> HashSet<Self::Vertex> {
+		let mut visited = HashSet::new();
+		visited.insert(from.clone());
+
+		let mut queue = VecDeque::new();
+		queue.push_back(from);
+
+		while let Some(v) = queue.pop_front() {
+			for w in self.successors(v) {
+				if visited.insert(w.clone()) {
+					queue.push_back(w)
+				}
+			}
+		}
+
+		visited
+	}
+
+	/// Computes the strongly connected component containing `v`, without
+	/// computing the whole decomposition.
+	///
+	/// Tarjan's algorithm is run starting from `v` only, without enumerating
+	/// the graph [`vertices`](Self::vertices), so only the vertices reachable
+	/// from `v` are visited. Contrarily to
+	/// [`strongly_connected_component_of`](Self::strongly_connected_component_of),
+	/// no predecessor is materialized.
+	///
+	/// ```
+	/// use scc_trait::Scc;
+	///
+	/// let graph = vec![vec![1], vec![2], vec![1, 3], vec![]];
+	/// let mut component = graph.component_of(1);
+	/// component.sort();
+	/// assert_eq!(component, [1, 2]);
+	/// ```
+	fn component_of(&self, v: Self::Vertex) -> Vec<Self::Vertex> {
+		tarjan::component_of(self, v)
+	}
+
+	/// Computes the strongly connected component containing `v`, without
+	/// computing the whole decomposition.
+	///
+	/// The component is the set of vertices reachable from `v` that can also
+	/// reach `v`. Since the graph only provides successors, this materializes
+	/// the predecessors of every vertex reachable from `v`.
+	/// [`component_of`](Self::component_of) computes the same component with
+	/// a single traversal and no predecessor map.
+	fn strongly_connected_component_of(&self, v: Self::Vertex) -> Vec<Self::Vertex> {
+		let reachable = self.reachable_set(v.clone());
+
+		let mut predecessors: HashMap<Self::Vertex, Vec<Self::Vertex>> = HashMap::new();
+		for u in &reachable {
+			for w in self.successors(u.clone()) {
+				predecessors.entry(w).or_default().push(u.clone())
+			}
+		}
+
+		let mut visited = HashSet::new();
+		visited.insert(v.clone());
+
+		let mut component = vec![v];
+		let mut i = 0;
+		while let Some(w) = component.get(i) {
+			for u in predecessors.get(w).into_iter().flatten() {
+				if visited.insert(u.clone()) {
+					component.push(u.clone())
+				}
+			}
+
+			i += 1
+		}
+
+		component
+	}
+
+	/// Checks if there is a non-empty path from `from` to `to`.
+	///
+	/// Paths must contain at least one edge, hence `has_path(v, v)` only
+	/// returns `true` if `v` is part of a cycle (or has a self-loop).
+	fn has_path(&self, from: Self::Vertex, to: Self::Vertex) -> bool {
+		let mut visited = HashSet::new();
+		let mut stack = vec![from];
+
+		while let Some(v) = stack.pop() {
+			for w in self.successors(v) {
+				if w == to {
+					return true;
+				}
+
+				if visited.insert(w.clone()) {
+					stack.push(w)
+				}
+			}
+		}
+
+		false
+	}
+
+	/// Sorts the vertices of the graph in topological order.
+	///
+	/// For every edge `u → v`, `u` comes before `v`. If the graph is not
+	/// acyclic, returns a cycle `[v0, ..., vn]` instead, where each vertex
+	/// is a predecessor of the next one, and `vn` a predecessor of `v0`.
+	fn topological_sort(&self) -> Result<Vec<Self::Vertex>, Vec<Self::Vertex>> {
+		topological_sort::sort(self)
+	}
+
+	/// Checks if the graph contains a cycle, including self-loops.
+	///
+	/// The search stops as soon as a cycle is found, without computing the
+	/// strongly connected components.
+	fn has_cycle(&self) -> bool {
+		topological_sort::search(self, |_| ()).is_err()
+	}
+
+	/// Checks if the graph is acyclic, meaning it has no cycle, including
+	/// self-loops.
+	///
+	/// This is the opposite of [`has_cycle`](Self::has_cycle).
+	fn is_dag(&self) -> bool {
+		!self.has_cycle()
+	}
+
+	/// Checks if the graph is strongly connected, meaning it has at most one
+	/// strongly connected component.
+	///
+	/// The search stops as soon as a second component is found, without
+	/// computing the condensation graph. An empty graph is strongly
+	/// connected.
+	///
+	/// ```
+	/// use scc_trait::Scc;
+	///
+	/// assert!(vec![vec![1], vec![0]].is_strongly_connected());
+	/// assert!(!vec![vec![1], vec![]].is_strongly_connected());
+	/// ```
+	fn is_strongly_connected(&self) -> bool {
+		self.scc_iter().nth(1).is_none()
+	}
+
+	/// Finds a cycle in the graph, if any.
+	///
+	/// The cycle `[v0, ..., vn]` is taken from the depth-first search path:
+	/// each vertex is a predecessor of the next one, and `vn` a predecessor of
+	/// `v0`. A self-loop is returned as a single vertex cycle.
+	///
+	/// ```
+	/// use scc_trait::Scc;
+	///
+	/// let graph = vec![vec![1], vec![2], vec![1]];
+	/// assert_eq!(graph.find_cycle(), Some(vec![1, 2]));
+	/// ```
+	fn find_cycle(&self) -> Option<Vec<Self::Vertex>> {
+		topological_sort::search(self, |_| ()).err()
+	}
+
+	/// Starts a resumable computation of the strongly connected components.
+	///
+	/// The returned state must be advanced using [`resume`](Self::resume).
+	/// This is useful for very long computations that may be interrupted,
+	/// since the state can be serialized with the `serde` feature.
+	fn strongly_connected_components_resumable(&self) -> SccProgress<Self::Vertex> {
+		SccProgress::new()
+	}
+
+	/// Resumes a computation started with
+	/// [`strongly_connected_components_resumable`](Self::strongly_connected_components_resumable),
+	/// running at most `max_steps` steps, where each step visits one vertex or
+	/// one edge.
+	///
+	/// Returns the strongly connected components once the computation is
+	/// complete, leaving `progress` reset to its initial state. Otherwise,
+	/// returns `None` and `progress` can be used to resume the computation
+	/// later.
+	fn resume(
+		&self,
+		progress: &mut SccProgress<Self::Vertex>,
+		max_steps: usize,
+	) -> Option<Components<Self::Vertex>> {
+		resumable::resume(self, progress, max_steps)
+	}
+
+	/// Returns the number of weakly connected components of the graph, where
+	/// edge directions are ignored.
+	///
+	/// This is cheaper than computing the components themselves, using a
+	/// union-find structure over the graph edges.
+	fn connected_component_count(&self) -> usize {
+		let mut sets = UnionFind::new();
+
+		for v in self.vertices() {
+			sets.insert(v);
+		}
+
+		self.for_each_edge(|v, w| sets.union(v, w));
+
+		sets.len()
+	}
+
+	/// Computes the weakly connected components of the graph, where edge
+	/// directions are ignored.
+	///
+	/// The result has the same query API as the strongly connected
+	/// components. There are no edges between weakly connected components,
+	/// and a component is cyclic if it contains at least one edge.
+	///
+	/// ```
+	/// use scc_trait::Scc;
+	///
+	/// let graph = vec![vec![1], vec![], vec![1], vec![]];
+	/// let components = graph.weakly_connected_components();
+	/// assert_eq!(components.len(), 2);
+	/// assert_eq!(components.get(&0).unwrap().len(), 3);
+	/// ```
+	fn weakly_connected_components(&self) -> Components<Self::Vertex> {
+		let mut sets = UnionFind::new();
+
+		for v in self.vertices() {
+			sets.insert(v);
+		}
+
+		self.for_each_edge(|v, w| sets.union(v, w));
+
+		Components::from_vertex_map(self, sets.into_vertex_map())
+	}
+
+	/// Calls `f` on every edge of the graph.
+	///
+	/// The default implementation iterates over the successors of every
+	/// vertex. Graph types storing a flat edge list may override it to avoid
+	/// creating an iterator per vertex.
+	fn for_each_edge<F: FnMut(Self::Vertex, Self::Vertex)>(&self, mut f: F) {
+		for v in self.vertices() {
+			for w in self.successors(v.clone()) {
+				f(v.clone(), w)
+			}
+		}
+	}
+
+	/// Returns the number of edges of the graph.
+	fn edge_count(&self) -> usize {
+		let mut count = 0;
+		self.for_each_edge(|_, _| count += 1);
+		count
+	}
+
+	/// Returns a view of the graph where only the edges `u → v` for which
+	/// `predicate(&u, &v)` returns `true` are kept.
+	///
+	/// ```
+	/// # use std::collections::HashSet;
+	/// # use scc_trait::Scc;
+	/// # let graph: Vec<Vec<usize>> = Vec::new();
+	/// # let cut: HashSet<(usize, usize)> = HashSet::new();
+	/// let components = graph
+	///   .filtered(|u, v| !cut.contains(&(*u, *v)))
+	///   .strongly_connected_components();
+	/// ```
+	fn filtered<F>(&self, predicate: F) -> Filtered<'_, Self, F>
+	where
+		F: Fn(&Self::Vertex, &Self::Vertex) -> bool,
+	{
+		Filtered::new(self, predicate)
+	}
+
+	/// Returns a view of the subgraph induced by the vertices for which
+	/// `predicate` returns `true`.
+	///
+	/// ```
+	/// use std::collections::HashSet;
+	/// use scc_trait::Scc;
+	///
+	/// let graph = vec![vec![1], vec![2], vec![0]];
+	/// let tagged = HashSet::from([0, 1]);
+	/// let components = graph
+	///   .node_filtered(|v| tagged.contains(v))
+	///   .strongly_connected_components();
+	/// assert_eq!(components.len(), 2);
+	/// ```
+	fn node_filtered<F>(&self, predicate: F) -> NodeFiltered<'_, Self, F>
+	where
+		F: Fn(&Self::Vertex) -> bool,
+	{
+		NodeFiltered::new(self, predicate)
+	}
+}
+
+/// Graph providing both the successors and predecessors of each vertex.
+///
+/// This allows computing strongly connected components using
+/// [Kosaraju's algorithm][1], which may be a better fit than Tarjan's
+/// algorithm for graphs already storing reverse edges.
+///
+/// [1]: <https://en.wikipedia.org/wiki/Kosaraju%27s_algorithm>
+pub trait SccBidirectional: Scc {
+	/// Returns an iterator over the predecessors of the given vertex.
+	fn predecessors(&self, v: Self::Vertex) -> impl '_ + IntoIterator<Item = Self::Vertex>;
+
+	/// Computes the strongly connected components of the graph using
+	/// Kosaraju's algorithm.
+	fn strongly_connected_components_kosaraju(&self) -> Components<Self::Vertex> {
+		kosaraju::scc(self)
+	}
+
+	/// Returns an iterator over the neighbors of the given vertex, ignoring
+	/// edge directions.
+	///
+	/// The successors of `v` are yielded first, followed by its predecessors.
+	/// A vertex that is both a successor and a predecessor of `v` is yielded
+	/// twice.
+	fn neighbors_undirected(&self, v: Self::Vertex) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+		self.successors(v.clone())
+			.into_iter()
+			.chain(self.predecessors(v))
+	}
+
+	/// Returns a view of this graph where the direction of every edge is
+	/// flipped.
+	///
+	/// ```
+	/// use scc_trait::{Scc, SccBidirectional};
+	///
+	/// struct Graph(Vec<Vec<usize>>);
+	///
+	/// impl Scc for Graph {
+	///   type Vertex = usize;
+	///
+	///   fn vertices(&self) -> impl '_ + IntoIterator<Item = usize> {
+	///     0..self.0.len()
+	///   }
+	///
+	///   fn successors(&self, v: usize) -> impl '_ + IntoIterator<Item = usize> {
+	///     self.0[v].iter().copied()
+	///   }
+	/// }
+	///
+	/// impl SccBidirectional for Graph {
+	///   fn predecessors(&self, v: usize) -> impl '_ + IntoIterator<Item = usize> {
+	///     (0..self.0.len()).filter(move |&u| self.0[u].contains(&v))
+	///   }
+	/// }
+	///
+	/// let graph = Graph(vec![vec![1], vec![2], vec![]]);
+	/// assert!(graph.has_path(0, 2));
+	/// assert!(graph.reversed().has_path(2, 0));
+	/// assert!(!graph.reversed().has_path(0, 2));
+	/// ```
+	fn reversed(&self) -> Reversed<'_, Self> {
+		Reversed::new(self)
+	}
+}
+
+/// Strongly connected components.
+///
+/// The vertex map uses the hasher `S`, which defaults to
+/// [`DefaultHashBuilder`]. Components using a different hasher are computed
+/// with [`Scc::strongly_connected_components_with_hasher`].
+pub struct Components<V, S = DefaultHashBuilder> {
+	/// Components list.
+	list: Vec<Vec<V>>,
+
+	/// Map from vertices to component index.
+	vertex_to_component: HashMap<V, usize, S>,
+
+	/// Component successors.
+	successors: Vec<HashSet<usize, DefaultHashBuilder>>,
+
+	/// Lazily computed data.
+	cache: Cache,
+}
+
+/// Data lazily computed from the components, and reset whenever the
+/// components are modified.
+#[derive(Default)]
+struct Cache {
+	/// Component depths.
+	depths: OnceLock<Vec<usize>>,
+
+	/// Component predecessors.
+	predecessors: OnceLock<Vec<HashSet<usize, DefaultHashBuilder>>>,
+}
+
+impl<V> Components<V> {
+	/// Creates an empty set of components.
+	pub fn empty() -> Self {
+		Self::new(Vec::new(), HashMap::default(), Vec::new())
+	}
+
+	/// Creates an empty set of components, with pre-allocated space for
+	/// `num_components` components and `num_vertices` vertices.
+	///
+	/// Components and condensation edges can then be added using
+	/// [`push_component`](Self::push_component) and
+	/// [`insert_successor`](Self::insert_successor).
+	pub fn with_capacity(num_components: usize, num_vertices: usize) -> Self {
+		Self::new(
+			Vec::with_capacity(num_components),
+			HashMap::with_capacity_and_hasher(num_vertices, DefaultHashBuilder::default()),
+			Vec::with_capacity(num_components),
+		)
+	}
+
+	/// Creates components from a partition of the graph vertices.
+	///
+	/// Each item of `partition` is a component. Condensation edges are
+	/// computed from the edges of `graph`, ignoring edges leading outside of
+	/// the partition.
+	///
+	/// The partition is not required to be made of strongly connected
+	/// components, in which case the condensation graph may be cyclic.
+	///
+	/// # Panics
+	///
+	/// Panics if a vertex appears in more than one component.
+	pub fn from_partition<G, C>(graph: &G, partition: impl IntoIterator<Item = C>) -> Self
+	where
+		G: ?Sized + Scc<Vertex = V>,
+		C: IntoIterator<Item = V>,
+		V: Clone + Eq + Hash,
+	{
+		let mut result = Self::empty();
+		for component in partition {
+			result.push_component(component);
+		}
+
+		result.successors =
+			Self::compute_successors(graph, &result.list, &result.vertex_to_component);
+		result
+	}
 }
 
-/// Strongly connected components.
-pub struct Components<V> {
-	/// Components list.
-	list: Vec<Vec<V>>,
+impl<V, S> Components<V, S> {
+	/// Creates components from their raw parts, with an empty cache.
+	///
+	/// Every construction path goes through this function so that lazily
+	/// computed fields are always properly initialized.
+	pub(crate) fn new(
+		list: Vec<Vec<V>>,
+		vertex_to_component: HashMap<V, usize, S>,
+		successors: Vec<HashSet<usize, DefaultHashBuilder>>,
+	) -> Self {
+		Self {
+			list,
+			vertex_to_component,
+			successors,
+			cache: Cache::default(),
+		}
+	}
+
+	/// Creates an empty set of components, using the given hasher to map
+	/// vertices to components.
+	pub fn with_hasher(hasher: S) -> Self {
+		Self::new(Vec::new(), HashMap::with_hasher(hasher), Vec::new())
+	}
+
+	/// Creates an empty set of components, with pre-allocated space for
+	/// `num_components` components and `num_vertices` vertices, using the
+	/// given hasher to map vertices to components.
+	pub fn with_capacity_and_hasher(num_components: usize, num_vertices: usize, hasher: S) -> Self {
+		Self::new(
+			Vec::with_capacity(num_components),
+			HashMap::with_capacity_and_hasher(num_vertices, hasher),
+			Vec::with_capacity(num_components),
+		)
+	}
+
+	/// Creates components from a map associating each vertex to its
+	/// component index.
+	///
+	/// Condensation edges are computed from the edges of `graph`, ignoring
+	/// edges leading to vertices absent from the map.
+	///
+	/// # Panics
+	///
+	/// Panics if the component indices do not form a contiguous range
+	/// starting at 0.
+	pub fn from_vertex_map<G>(graph: &G, vertex_to_component: HashMap<V, usize, S>) -> Self
+	where
+		G: ?Sized + Scc<Vertex = V>,
+		V: Clone + Eq + Hash,
+		S: BuildHasher,
+	{
+		let len = vertex_to_component.values().max().map_or(0, |&i| i + 1);
+		let mut list = Vec::new();
+		list.resize_with(len, Vec::new);
+
+		for (v, &i) in &vertex_to_component {
+			list[i].push(v.clone())
+		}
+
+		assert!(
+			list.iter().all(|component| !component.is_empty()),
+			"non-contiguous component indices"
+		);
+
+		let successors = Self::compute_successors(graph, &list, &vertex_to_component);
+		Self::new(list, vertex_to_component, successors)
+	}
+
+	/// Computes the successors of each component from the edges of `graph`.
+	///
+	/// Edges leading to vertices without component are ignored.
+	pub(crate) fn compute_successors<G>(
+		graph: &G,
+		list: &[Vec<V>],
+		vertex_to_component: &HashMap<V, usize, S>,
+	) -> Vec<HashSet<usize, DefaultHashBuilder>>
+	where
+		G: ?Sized + Scc<Vertex = V>,
+		V: Clone + Eq + Hash,
+		S: BuildHasher,
+	{
+		list.iter()
+			.map(|component| {
+				component
+					.iter()
+					.flat_map(|v| {
+						graph
+							.successors(v.clone())
+							.into_iter()
+							.filter_map(|w| vertex_to_component.get(&w).copied())
+					})
+					.collect()
+			})
+			.collect()
+	}
+
+	/// Adds a new component made of the given vertices, with no successors.
+	///
+	/// Returns the index of the new component.
+	///
+	/// # Panics
+	///
+	/// Panics if one of the vertices already belongs to a component.
+	pub fn push_component(&mut self, vertices: impl IntoIterator<Item = V>) -> usize
+	where
+		V: Clone + Eq + Hash,
+		S: BuildHasher,
+	{
+		let i = self.list.len();
+		let component: Vec<V> = vertices.into_iter().collect();
+
+		assert!(
+			component
+				.iter()
+				.all(|v| !self.vertex_to_component.contains_key(v)),
+			"vertex already belongs to a component"
+		);
+
+		self.vertex_to_component
+			.extend(component.iter().map(|v| (v.clone(), i)));
+
+		self.list.push(component);
+		self.successors.push(HashSet::default());
+		self.cache = Cache::default();
+		i
+	}
+
+	/// Adds the condensation edge `i → j`.
+	///
+	/// Returns `false` if the edge was already present.
+	///
+	/// # Panics
+	///
+	/// Panics if either `i` or `j` is out of bounds.
+	pub fn insert_successor(&mut self, i: usize, j: usize) -> bool {
+		assert!(j < self.list.len(), "component index out of bounds");
+		let inserted = self.successors[i].insert(j);
+
+		if inserted {
+			self.cache = Cache::default()
+		}
+
+		inserted
+	}
+
+	/// Returns the number of strongly connected components.
+	pub fn len(&self) -> usize {
+		self.list.len()
+	}
+
+	/// Checks if there are no components.
+	pub fn is_empty(&self) -> bool {
+		self.list.is_empty()
+	}
+
+	/// Returns an iterator over the strongly connected components.
+	pub fn iter(&self) -> Iter<'_, V> {
+		Iter(self.list.iter())
+	}
+
+	/// Returns the index of the given vertex's strongly connected component.
+	pub fn vertex_component_index(&self, v: &V) -> Option<usize>
+	where
+		V: Eq + Hash,
+		S: BuildHasher,
+	{
+		self.vertex_to_component.get(v).cloned()
+	}
+
+	/// Checks if vertices `u` and `v` belong to the same component.
+	///
+	/// Returns `false` if either vertex has no component.
+	pub fn same_component(&self, u: &V, v: &V) -> bool
+	where
+		V: Eq + Hash,
+		S: BuildHasher,
+	{
+		match (
+			self.vertex_component_index(u),
+			self.vertex_component_index(v),
+		) {
+			(Some(i), Some(j)) => i == j,
+			_ => false,
+		}
+	}
+
+	/// Checks if vertex `v` is reachable from vertex `u`.
+	///
+	/// This is the case if both vertices belong to the same component, or if
+	/// the component of `v` is reachable from the component of `u` in the
+	/// condensation graph. In particular, every vertex is reachable from
+	/// itself. Returns `false` if either vertex has no component.
+	///
+	/// Each call searches the condensation graph. For many queries, use
+	/// [`transitive_closure`](Self::transitive_closure) instead.
+	pub fn can_reach(&self, u: &V, v: &V) -> bool
+	where
+		V: Eq + Hash,
+		S: BuildHasher,
+	{
+		let (Some(i), Some(j)) = (
+			self.vertex_component_index(u),
+			self.vertex_component_index(v),
+		) else {
+			return false;
+		};
+
+		let mut visited = vec![false; self.list.len()];
+		visited[i] = true;
+		let mut stack = vec![i];
+		while let Some(k) = stack.pop() {
+			if k == j {
+				return true;
+			}
+
+			for &l in &self.successors[k] {
+				if !visited[l] {
+					visited[l] = true;
+					stack.push(l)
+				}
+			}
+		}
+
+		false
+	}
+
+	/// Returns the component with the given index `i`.
+	pub fn get_by_index(&self, i: usize) -> Option<&[V]> {
+		self.list.get(i).map(Vec::as_slice)
+	}
+
+	/// Returns the number of vertices in the component with index `i`.
+	pub fn component_size(&self, i: usize) -> Option<usize> {
+		self.list.get(i).map(Vec::len)
+	}
+
+	/// Returns the index of the component with the most vertices.
+	///
+	/// If several components have the same maximum size, the first one is
+	/// returned. Returns `None` if there are no components.
+	pub fn largest_component(&self) -> Option<usize> {
+		self.list
+			.iter()
+			.enumerate()
+			.rev()
+			.max_by_key(|(_, component)| component.len())
+			.map(|(i, _)| i)
+	}
+
+	/// Returns the number of components of each size.
+	pub fn size_histogram(&self) -> BTreeMap<usize, usize> {
+		let mut histogram = BTreeMap::new();
+		for component in &self.list {
+			*histogram.entry(component.len()).or_default() += 1
+		}
+
+		histogram
+	}
+
+	/// Return the given vertex's strongly connected component.
+	pub fn get(&self, v: &V) -> Option<&[V]>
+	where
+		V: Eq + Hash,
+		S: BuildHasher,
+	{
+		self.get_by_index(self.vertex_component_index(v)?)
+	}
+
+	pub fn successors(&self, i: usize) -> Option<impl '_ + Iterator<Item = usize>> {
+		self.successors.get(i).map(|s| s.iter().cloned())
+	}
+
+	/// Checks if the component with index `i` contains a cycle.
+	///
+	/// This is the case if the component has an internal edge, meaning it
+	/// has more than one vertex, or a single vertex with a self-loop. Returns
+	/// `false` if `i` is out of bounds.
+	pub fn is_cyclic(&self, i: usize) -> bool {
+		self.successors.get(i).is_some_and(|s| s.contains(&i))
+	}
+
+	/// Checks if the component with index `i` is trivial, meaning it is made
+	/// of a single vertex without self-loop.
+	///
+	/// For components computed from a graph, this is the opposite of
+	/// [`is_cyclic`](Self::is_cyclic). Returns `None` if `i` is out of bounds.
+	pub fn is_trivial(&self, i: usize) -> Option<bool> {
+		let component = self.list.get(i)?;
+		Some(component.len() == 1 && !self.successors[i].contains(&i))
+	}
+
+	/// Returns, for each component, whether it contains a cycle.
+	///
+	/// See [`is_cyclic`](Self::is_cyclic).
+	///
+	/// ```
+	/// use scc_trait::Scc;
+	///
+	/// let graph = vec![vec![0], vec![2], vec![1], vec![]];
+	/// let components = graph.strongly_connected_components();
+	/// let cyclic = components.cyclic_components();
+	/// assert!(cyclic[components.vertex_component_index(&0).unwrap()]);
+	/// assert!(cyclic[components.vertex_component_index(&1).unwrap()]);
+	/// assert!(!cyclic[components.vertex_component_index(&3).unwrap()]);
+	/// ```
+	pub fn cyclic_components(&self) -> Vec<bool> {
+		(0..self.list.len()).map(|i| self.is_cyclic(i)).collect()
+	}
+
+	fn remove_indirect_successors(&self, result: &mut HashSet<usize>, i: usize) {
+		for j in self.successors(i).unwrap() {
+			result.remove(&j);
+			self.remove_indirect_successors(result, j);
+		}
+	}
+
+	#[deprecated(note = "may take exponential time, use `transitive_reduction` instead")]
+	pub fn direct_successors(&self, i: usize) -> Option<HashSet<usize>> {
+		let mut result: HashSet<_> = self.successors(i)?.collect();
+
+		for j in self.successors(i).unwrap() {
+			self.remove_indirect_successors(&mut result, j);
+		}
+
+		Some(result)
+	}
+
+	/// Computes the transitive reduction of the condensation graph.
+	///
+	/// Returns the direct successors of each component: the successors `j`
+	/// of `i` that cannot be reached from `i` through another successor.
+	/// Self-loops are removed. Reachability sets are computed once for all
+	/// components, in reverse topological order.
+	///
+	/// ```
+	/// use scc_trait::Scc;
+	///
+	/// let graph = vec![vec![1, 2], vec![2], vec![]];
+	/// let components = graph.strongly_connected_components();
+	/// let reduction = components.transitive_reduction();
+	/// let i = components.vertex_component_index(&0).unwrap();
+	/// let j = components.vertex_component_index(&1).unwrap();
+	/// assert_eq!(reduction[i], [j].into());
+	/// ```
+	///
+	/// # Panics
+	///
+	/// Panics if the condensation graph is cyclic.
+	pub fn transitive_reduction(&self) -> Vec<HashSet<usize>> {
+		let order = self.topological_order();
+		let mut position = vec![0; order.len()];
+		for (p, &i) in order.iter().enumerate() {
+			position[i] = p
+		}
+
+		let mut reachable: Vec<HashSet<usize>> = vec![HashSet::new(); order.len()];
+		let mut result: Vec<HashSet<usize>> = vec![HashSet::new(); order.len()];
+
+		for &i in order.iter().rev() {
+			// If `k` is reachable from another successor `j`, then `j` comes
+			// first in topological order.
+			let mut successors: Vec<usize> = self.successors[i]
+				.iter()
+				.copied()
+				.filter(|&j| j != i)
+				.collect();
+			successors.sort_unstable_by_key(|&j| position[j]);
+
+			let mut r = HashSet::new();
+			for j in successors {
+				if !r.contains(&j) {
+					result[i].insert(j);
+					r.insert(j);
+					r.extend(reachable[j].iter().copied())
+				}
+			}
+
+			reachable[i] = r
+		}
+
+		result
+	}
+
+	/// Computes the transitive closure of the condensation graph.
+	///
+	/// The result stores, for each component, the set of components reachable
+	/// from it as a packed bitset, answering reachability queries in constant
+	/// time. This requires `O(len()²)` bits of memory.
+	///
+	/// ```
+	/// use scc_trait::Scc;
+	///
+	/// let graph = vec![vec![1], vec![2], vec![]];
+	/// let components = graph.strongly_connected_components();
+	/// let closure = components.transitive_closure();
+	/// let i = components.vertex_component_index(&0).unwrap();
+	/// let j = components.vertex_component_index(&2).unwrap();
+	/// assert!(closure.reaches(i, j));
+	/// assert!(!closure.reaches(j, i));
+	/// ```
+	///
+	/// # Panics
+	///
+	/// Panics if the condensation graph is cyclic.
+	pub fn transitive_closure(&self) -> TransitiveClosure {
+		let mut closure = TransitiveClosure::new(self.list.len());
+
+		for &i in self.topological_order().iter().rev() {
+			for &j in &self.successors[i] {
+				closure.union_row(i, j)
+			}
+		}
+
+		closure
+	}
+
+	/// Returns the adjacency matrix of the condensation graph.
+	///
+	/// The matrix has `len()` rows and columns, with `matrix[i][j]` set to
+	/// `true` if `j` is a successor of component `i`. The diagonal is set for
+	/// cyclic components.
+	///
+	/// This requires `O(len()²)` memory and is only intended for small
+	/// condensations (debugging, printing, etc.).
+	pub fn adjacency_matrix(&self) -> Vec<Vec<bool>> {
+		self.successors
+			.iter()
+			.map(|successors| {
+				let mut row = vec![false; self.list.len()];
+				for &j in successors {
+					row[j] = true;
+				}
+				row
+			})
+			.collect()
+	}
+
+	/// Returns the condensation graph, where each vertex is a component index.
+	///
+	/// The result implements [`Scc`], so any algorithm written against the
+	/// trait can be run on the condensation graph.
+	///
+	/// ```
+	/// use scc_trait::Scc;
+	///
+	/// let graph = vec![vec![1], vec![0, 2], vec![]];
+	/// let components = graph.strongly_connected_components();
+	/// let condensation = components.condensation();
+	/// assert_eq!(condensation.strongly_connected_components().len(), 2);
+	/// assert!(condensation.has_cycle());
+	/// ```
+	pub fn condensation(&self) -> Condensation<'_, V, S> {
+		Condensation::new(self)
+	}
+
+	/// Returns the depth of each component.
+	///
+	/// The depth of a component is the maximum of the depth of its predecessors
+	/// plus 1. A component with no predecessors has depth 0.
+	///
+	/// Depths are propagated along a [topological order](Self::topological_order),
+	/// in linear time.
+	///
+	/// # Panics
+	///
+	/// Panics if the condensation graph is cyclic.
+	pub fn depths(&self) -> Vec<usize> {
+		let mut depth = vec![0; self.list.len()];
+
+		for i in self.topological_order() {
+			for &j in &self.successors[i] {
+				if j != i {
+					depth[j] = depth[j].max(depth[i] + 1)
+				}
+			}
+		}
+
+		depth
+	}
+
+	/// Returns the height of each component.
+	///
+	/// The height of a component is the maximum of the height of its
+	/// successors plus 1. A component with no successors has height 0. This
+	/// is the length of the longest path from the component to a sink, and
+	/// complements [`depths`](Self::depths): the slack of a component is
+	/// `diameter - depth - height`.
+	///
+	/// # Panics
+	///
+	/// Panics if the condensation graph is cyclic.
+	pub fn heights(&self) -> Vec<usize> {
+		let mut height = vec![0; self.list.len()];
+
+		for &i in self.topological_order().iter().rev() {
+			height[i] = self.successors[i]
+				.iter()
+				.filter(|&&j| j != i)
+				.map(|&j| height[j] + 1)
+				.max()
+				.unwrap_or(0)
+		}
+
+		height
+	}
+
+	/// Returns the cached component depths, computing them if necessary.
+	fn cached_depths(&self) -> &[usize] {
+		self.cache.depths.get_or_init(|| self.depths())
+	}
+
+	/// Returns the cached component predecessors, computing them if necessary.
+	fn cached_predecessors(&self) -> &[HashSet<usize, DefaultHashBuilder>] {
+		self.cache
+			.predecessors
+			.get_or_init(|| self.predecessors_with_hasher())
+	}
+
+	/// Checks if the component with index `i` is a source of the condensation
+	/// graph, meaning it has no predecessors other than itself.
+	///
+	/// Returns `None` if `i` is out of bounds.
+	pub fn is_source(&self, i: usize) -> Option<bool> {
+		let predecessors = self.cached_predecessors().get(i)?;
+		Some(predecessors.iter().all(|&j| j == i))
+	}
+
+	/// Checks if the component with index `i` is a sink of the condensation
+	/// graph, meaning it has no successors other than itself.
+	///
+	/// Returns `None` if `i` is out of bounds.
+	pub fn is_sink(&self, i: usize) -> Option<bool> {
+		let successors = self.successors.get(i)?;
+		Some(successors.iter().all(|&j| j == i))
+	}
+
+	/// Returns the indices of the source components of the condensation
+	/// graph, in increasing order.
+	///
+	/// See [`is_source`](Self::is_source).
+	pub fn roots(&self) -> Vec<usize> {
+		(0..self.list.len())
+			.filter(|&i| self.is_source(i).unwrap())
+			.collect()
+	}
+
+	/// Returns the indices of the sink components of the condensation graph,
+	/// in increasing order.
+	///
+	/// See [`is_sink`](Self::is_sink).
+	pub fn leaves(&self) -> Vec<usize> {
+		(0..self.list.len())
+			.filter(|&i| self.is_sink(i).unwrap())
+			.collect()
+	}
+
+	/// Returns the indices of the components reachable from component `i`
+	/// in the condensation graph, excluding `i` itself.
+	///
+	/// Returns `None` if `i` is out of bounds.
+	///
+	/// ```
+	/// use scc_trait::Scc;
+	///
+	/// let graph = vec![vec![1], vec![2], vec![]];
+	/// let components = graph.strongly_connected_components();
+	/// let i = components.vertex_component_index(&0).unwrap();
+	/// let j = components.vertex_component_index(&2).unwrap();
+	/// assert_eq!(components.descendants(i).unwrap().len(), 2);
+	/// assert_eq!(components.ancestors(j).unwrap().len(), 2);
+	/// ```
+	pub fn descendants(&self, i: usize) -> Option<HashSet<usize>> {
+		(i < self.list.len()).then(|| Self::reach(&self.successors, i))
+	}
+
+	/// Returns the indices of the components from which component `i` is
+	/// reachable in the condensation graph, excluding `i` itself.
+	///
+	/// Returns `None` if `i` is out of bounds.
+	pub fn ancestors(&self, i: usize) -> Option<HashSet<usize>> {
+		(i < self.list.len()).then(|| Self::reach(self.cached_predecessors(), i))
+	}
+
+	/// Returns the components reachable from `i` following `edges`,
+	/// excluding `i`.
+	fn reach<H>(edges: &[HashSet<usize, H>], i: usize) -> HashSet<usize> {
+		let mut result = HashSet::new();
+		let mut stack = vec![i];
+		while let Some(j) = stack.pop() {
+			for &k in &edges[j] {
+				if k != i && result.insert(k) {
+					stack.push(k)
+				}
+			}
+		}
+
+		result
+	}
+
+	/// Returns the depth of the component with index `i`.
+	///
+	/// Depths are computed for all components on the first call, and cached
+	/// for subsequent calls.
+	///
+	/// See [`depths`](Self::depths) for the definition of the depth.
+	pub fn depth_of_index(&self, i: usize) -> Option<usize> {
+		self.cached_depths().get(i).copied()
+	}
+
+	/// Returns the depth of the given vertex's strongly connected component.
+	///
+	/// See [`depth_of_index`](Self::depth_of_index).
+	pub fn depth_of(&self, v: &V) -> Option<usize>
+	where
+		V: Eq + Hash,
+		S: BuildHasher,
+	{
+		self.depth_of_index(self.vertex_component_index(v)?)
+	}
+
+	pub fn predecessors(&self) -> Vec<HashSet<usize>> {
+		self.predecessors_with_hasher()
+	}
+
+	/// Computes the predecessors of each component, using `H` for the sets.
+	fn predecessors_with_hasher<H: BuildHasher + Default>(&self) -> Vec<HashSet<usize, H>> {
+		let mut predecessors = Vec::new();
+		predecessors.resize_with(self.list.len(), HashSet::default);
+
+		for (i, successors) in self.successors.iter().enumerate() {
+			for &j in successors {
+				predecessors[j].insert(i);
+			}
+		}
+
+		predecessors
+	}
+
+	/// Order components by depth.
+	///
+	/// The depth of a component is the maximum of the depth of its predecessors
+	/// plus 1. A component with no predecessors has depth 0.
+	pub fn order_by_depth(&self) -> Vec<usize> {
+		let depth = self.cached_depths();
+		let mut ordered_components: Vec<_> = (0..self.list.len()).collect();
+		ordered_components.sort_unstable_by_key(|i| depth[*i]);
+		ordered_components
+	}
+
+	/// Groups components by depth.
+	///
+	/// Layer `d` contains the indices of the components with depth `d`, in
+	/// increasing order. Components of a same layer do not depend on each
+	/// other, and only have predecessors in previous layers, so each layer can
+	/// be processed in parallel once the previous ones are done.
+	///
+	/// ```
+	/// use scc_trait::Scc;
+	///
+	/// let graph = vec![vec![1, 2], vec![], vec![]];
+	/// let components = graph.strongly_connected_components();
+	/// let layers = components.layers();
+	/// assert_eq!(layers.len(), 2);
+	/// assert_eq!(layers[1].len(), 2);
+	/// ```
+	pub fn layers(&self) -> Vec<Vec<usize>> {
+		let depths = self.cached_depths();
+		let mut layers = vec![Vec::new(); depths.iter().max().map_or(0, |&d| d + 1)];
+		for (i, &d) in depths.iter().enumerate() {
+			layers[d].push(i)
+		}
+
+		layers
+	}
+
+	/// Groups vertices by the depth of their component.
+	///
+	/// This is [`layers`](Self::layers) where each component is replaced by
+	/// its vertices.
+	pub fn vertex_layers(&self) -> Vec<Vec<V>>
+	where
+		V: Clone,
+	{
+		self.layers()
+			.into_iter()
+			.map(|layer| {
+				layer
+					.into_iter()
+					.flat_map(|i| self.list[i].iter().cloned())
+					.collect()
+			})
+			.collect()
+	}
+
+	/// Sorts the vertices of each component by order of first appearance in
+	/// the graph's [`vertices`](Scc::vertices).
+	///
+	/// Tarjan's algorithm emits component members in stack-pop order, which
+	/// is not meaningful to a reader. This provides a stable order suitable for
+	/// reproducible output. Vertices not enumerated by `vertices()` are placed
+	/// last, in their original relative order.
+	pub fn sort_components_by_discovery<G>(&mut self, graph: &G)
+	where
+		G: ?Sized + Scc<Vertex = V>,
+		V: Clone + Eq + Hash,
+		S: BuildHasher,
+	{
+		let mut position: HashMap<V, usize> = HashMap::new();
+		for v in graph.vertices() {
+			let len = position.len();
+			position.entry(v).or_insert(len);
+		}
+
+		for component in &mut self.list {
+			component.sort_by_key(|v| position.get(v).copied().unwrap_or(usize::MAX))
+		}
+	}
+
+	/// Returns the number of distinct paths from component `a` to component
+	/// `b` in the condensation graph.
+	///
+	/// Self-loops are ignored, so there is exactly one (empty) path from a
+	/// component to itself. Returns 0 if either index is out of bounds. The
+	/// count saturates at [`u128::MAX`] since it can grow exponentially with
+	/// the size of the condensation.
+	///
+	/// # Panics
+	///
+	/// Panics if a cycle of the condensation graph is reachable from `a`,
+	/// which can only happen if edges were added using
+	/// [`insert_successor`](Self::insert_successor) or if the components
+	/// were built with [`from_partition`](Components::from_partition).
+	pub fn path_count(&self, a: usize, b: usize) -> u128 {
+		if a >= self.list.len() || b >= self.list.len() {
+			return 0;
+		}
+
+		let mut count: Vec<Option<u128>> = vec![None; self.list.len()];
+		let mut stack = vec![(a, false)];
+
+		while let Some((i, visited)) = stack.pop() {
+			if visited {
+				let c = if i == b {
+					1
+				} else {
+					self.successors[i]
+						.iter()
+						.filter(|&&j| j != i)
+						.fold(0u128, |c, &j| c.saturating_add(count[j].unwrap()))
+				};
+
+				count[i] = Some(c)
+			} else if count[i].is_none() {
+				stack.push((i, true));
+				for &j in &self.successors[i] {
+					if j != i && count[j].is_none() {
+						stack.push((j, false))
+					}
+				}
+			}
+		}
+
+		count[a].unwrap()
+	}
+
+	/// Returns a structural summary of the components.
+	pub fn summary(&self) -> ComponentsSummary {
+		let cyclic_components = (0..self.list.len()).filter(|&i| self.is_cyclic(i)).count();
+
+		ComponentsSummary {
+			total_components: self.list.len(),
+			trivial_components: self.list.len() - cyclic_components,
+			cyclic_components,
+			largest_component_size: self.largest_component().map_or(0, |i| self.list[i].len()),
+			condensation_depth: self.cached_depths().iter().copied().max().unwrap_or(0),
+			condensation_edges: self
+				.successors
+				.iter()
+				.enumerate()
+				.map(|(i, successors)| successors.iter().filter(|&&j| j != i).count())
+				.sum(),
+		}
+	}
+
+	/// Returns an iterator over the edges `(i, j)` between distinct components
+	/// of the condensation graph, in no particular order.
+	pub fn edges(&self) -> impl '_ + Iterator<Item = (usize, usize)> {
+		self.successors
+			.iter()
+			.enumerate()
+			.flat_map(|(i, successors)| {
+				successors
+					.iter()
+					.filter(move |&&j| j != i)
+					.map(move |&j| (i, j))
+			})
+	}
+
+	/// Returns the edges between distinct components of the condensation
+	/// graph, sorted by `(from, to)`.
+	///
+	/// Use this instead of [`edges`](Self::edges) when a deterministic output
+	/// is required.
+	pub fn edges_sorted(&self) -> Vec<(usize, usize)> {
+		let mut edges: Vec<_> = self.edges().collect();
+		edges.sort_unstable();
+		edges
+	}
+
+	/// Returns an iterator over the edges `u → v` of `graph` leaving the
+	/// component with index `i`.
+	///
+	/// Yields every edge where `u` belongs to component `i` and `v` belongs to
+	/// another component. Edges leading to vertices without component are
+	/// ignored. Yields nothing if `i` is out of bounds.
+	///
+	/// ```
+	/// use scc_trait::Scc;
+	///
+	/// let graph = vec![vec![1], vec![0, 2], vec![]];
+	/// let components = graph.strongly_connected_components();
+	/// let i = components.vertex_component_index(&0).unwrap();
+	/// let edges: Vec<_> = components.successor_edges(&graph, i).collect();
+	/// assert_eq!(edges, [(1, 2)]);
+	/// ```
+	pub fn successor_edges<'a, G>(
+		&'a self,
+		graph: &'a G,
+		i: usize,
+	) -> impl 'a + Iterator<Item = (V, V)>
+	where
+		G: ?Sized + Scc<Vertex = V>,
+		V: Clone + Eq + Hash,
+		S: BuildHasher,
+	{
+		self.list.get(i).into_iter().flatten().flat_map(move |u| {
+			graph
+				.successors(u.clone())
+				.into_iter()
+				.filter(move |v| self.vertex_to_component.get(v).is_some_and(|&j| j != i))
+				.map(move |v| (u.clone(), v))
+		})
+	}
+
+	/// Counts the edges of `graph` connecting each pair of components.
+	///
+	/// Maps each condensation edge `(i, j)` to the number of edges `u → v` of
+	/// `graph` where `u` belongs to component `i` and `v` to component `j`.
+	/// Edges inside a component are counted under `(i, i)`. Edges leading to
+	/// vertices without component are ignored.
+	///
+	/// ```
+	/// use scc_trait::Scc;
+	///
+	/// let graph = vec![vec![1], vec![0, 2], vec![], vec![2]];
+	/// let components = graph.strongly_connected_components();
+	/// let counts = components.edge_multiplicities(&graph);
+	/// let i = components.vertex_component_index(&0).unwrap();
+	/// let j = components.vertex_component_index(&2).unwrap();
+	/// assert_eq!(counts[&(i, i)], 2);
+	/// assert_eq!(counts[&(i, j)], 1);
+	/// ```
+	pub fn edge_multiplicities<G>(&self, graph: &G) -> HashMap<(usize, usize), usize>
+	where
+		G: ?Sized + Scc<Vertex = V>,
+		V: Clone + Eq + Hash,
+		S: BuildHasher,
+	{
+		let mut counts = HashMap::new();
+
+		for (i, component) in self.list.iter().enumerate() {
+			for u in component {
+				for v in graph.successors(u.clone()) {
+					if let Some(&j) = self.vertex_to_component.get(&v) {
+						*counts.entry((i, j)).or_default() += 1
+					}
+				}
+			}
+		}
+
+		counts
+	}
+
+	/// Returns the condensation graph as a labeled adjacency list.
+	///
+	/// Each component `i` is mapped to `(label(i), successors)` where
+	/// `successors` contains the labels of its successors (excluding itself),
+	/// sorted by component index.
+	pub fn labeled_condensation<F: Fn(usize) -> String>(
+		&self,
+		label: F,
+	) -> Vec<(String, Vec<String>)> {
+		self.successors
+			.iter()
+			.enumerate()
+			.map(|(i, successors)| {
+				let mut successors: Vec<_> =
+					successors.iter().copied().filter(|&j| j != i).collect();
+				successors.sort_unstable();
+				(label(i), successors.into_iter().map(&label).collect())
+			})
+			.collect()
+	}
+
+	/// Returns the component indices in topological order.
+	///
+	/// For every edge `i → j` between distinct components, `i` comes before
+	/// `j`. Among the valid orders, this always returns the same one,
+	/// independently of the hash iteration order.
+	///
+	/// # Panics
+	///
+	/// Panics if the condensation graph is cyclic, which can only happen if
+	/// edges were added using [`insert_successor`](Self::insert_successor).
+	pub fn topological_order(&self) -> Vec<usize> {
+		self.try_topological_order()
+			.expect("cyclic condensation graph")
+	}
+
+	/// Returns an iterator over the components in topological order, with
+	/// their index.
+	///
+	/// See [`topological_order`](Self::topological_order).
+	///
+	/// ```
+	/// use scc_trait::Scc;
+	///
+	/// let graph = vec![vec![1], vec![2], vec![1]];
+	/// let components = graph.strongly_connected_components();
+	/// let order: Vec<_> = components.iter_topological().map(|(_, c)| c.len()).collect();
+	/// assert_eq!(order, [1, 2]);
+	/// ```
+	///
+	/// # Panics
+	///
+	/// Panics if the condensation graph is cyclic.
+	pub fn iter_topological(&self) -> impl '_ + Iterator<Item = (usize, &[V])> {
+		self.topological_order()
+			.into_iter()
+			.map(|i| (i, self.list[i].as_slice()))
+	}
+
+	/// Returns every vertex, ordered such that each vertex comes after its
+	/// dependencies.
+	///
+	/// An edge `u → v` is interpreted as "`u` depends on `v`". Components are
+	/// concatenated in reverse [`topological_order`](Self::topological_order),
+	/// so every vertex comes after all the successors of its component
+	/// (vertices of a same cyclic component are in no particular order).
+	///
+	/// ```
+	/// use scc_trait::Scc;
+	///
+	/// let graph = vec![vec![1, 2], vec![2], vec![]];
+	/// let components = graph.strongly_connected_components();
+	/// assert_eq!(components.dependency_order(), [2, 1, 0]);
+	/// ```
+	///
+	/// # Panics
+	///
+	/// Panics if the condensation graph is cyclic.
+	pub fn dependency_order(&self) -> Vec<V>
+	where
+		V: Clone,
+	{
+		self.topological_order()
+			.into_iter()
+			.rev()
+			.flat_map(|i| self.list[i].iter().cloned())
+			.collect()
+	}
+
+	/// Checks that the condensation graph is acyclic, ignoring self-loops.
+	///
+	/// This is always true for components computed from a graph, and is
+	/// checked in debug builds. It can only be broken by adding edges using
+	/// [`insert_successor`](Self::insert_successor).
+	pub fn is_condensation_acyclic(&self) -> bool {
+		self.try_topological_order().is_some()
+	}
+
+	/// Returns the component indices in topological order, or `None` if the
+	/// condensation graph is cyclic.
+	fn try_topological_order(&self) -> Option<Vec<usize>> {
+		let mut in_degree = vec![0usize; self.list.len()];
+		for (_, j) in self.edges() {
+			in_degree[j] += 1
+		}
+
+		let mut ready: BinaryHeap<usize> = in_degree
+			.iter()
+			.enumerate()
+			.filter_map(|(i, &d)| (d == 0).then_some(i))
+			.collect();
+
+		let mut order = Vec::with_capacity(self.list.len());
+		while let Some(i) = ready.pop() {
+			order.push(i);
+			for &j in &self.successors[i] {
+				if j != i {
+					in_degree[j] -= 1;
+					if in_degree[j] == 0 {
+						ready.push(j)
+					}
+				}
+			}
+		}
+
+		(order.len() == self.list.len()).then_some(order)
+	}
+
+	/// Reindexes the components so that index order is a topological order.
+	///
+	/// After this, every edge `i → j` between distinct components satisfies
+	/// `i < j`. See [`topological_order`](Self::topological_order).
+	pub fn into_topological(self) -> Self {
+		let order = self.topological_order();
+		self.reorder(&order)
+	}
+
+	/// Reindexes the components such that the new component `k` is the old
+	/// component `order[k]`.
+	///
+	/// The `order` slice must be a permutation of the component indices.
+	fn reorder(mut self, order: &[usize]) -> Self {
+		let mut new_index = vec![0; order.len()];
+		for (k, &i) in order.iter().enumerate() {
+			new_index[i] = k;
+		}
+
+		let list = order
+			.iter()
+			.map(|&i| std::mem::take(&mut self.list[i]))
+			.collect();
+
+		let successors = order
+			.iter()
+			.map(|&i| self.successors[i].iter().map(|&j| new_index[j]).collect())
+			.collect();
+
+		for i in self.vertex_to_component.values_mut() {
+			*i = new_index[*i]
+		}
+
+		Self::new(list, self.vertex_to_component, successors)
+	}
+
+	/// Groups the components by vertex key.
+	///
+	/// Maps each key returned by `key` to the indices of the components
+	/// containing at least one vertex with this key, in increasing order.
+	pub fn group_by<K: Eq + Hash, F: Fn(&V) -> K>(&self, key: F) -> HashMap<K, Vec<usize>> {
+		let mut groups: HashMap<K, Vec<usize>> = HashMap::new();
+
+		for (i, component) in self.list.iter().enumerate() {
+			for v in component {
+				let group = groups.entry(key(v)).or_default();
+				if group.last() != Some(&i) {
+					group.push(i)
+				}
+			}
+		}
+
+		groups
+	}
+
+	/// Returns the internal edge density of the component with index `i`.
+	///
+	/// This is the number of edges between distinct vertices of the
+	/// component, divided by the number of possible such edges `n(n - 1)`. A
+	/// density close to 1 indicates an almost complete component.
+	///
+	/// Returns `None` if `i` is out of bounds or if the component has only one
+	/// vertex.
+	pub fn internal_density<G>(&self, graph: &G, i: usize) -> Option<f64>
+	where
+		G: ?Sized + Scc<Vertex = V>,
+		V: Clone + Eq + Hash,
+		S: BuildHasher,
+	{
+		let component = self.list.get(i)?;
+		let n = component.len();
+		if n < 2 {
+			return None;
+		}
+
+		let edges: usize = component
+			.iter()
+			.map(|u| {
+				graph
+					.successors(u.clone())
+					.into_iter()
+					.filter(|v| v != u && self.vertex_to_component.get(v) == Some(&i))
+					.collect::<HashSet<_>>()
+					.len()
+			})
+			.sum();
+
+		Some(edges as f64 / (n * (n - 1)) as f64)
+	}
+
+	/// Returns the diameter of the condensation graph.
+	///
+	/// Since the condensation graph is acyclic, this is the length of its
+	/// longest path, ignoring self-loops, which is also the maximum component
+	/// depth. Returns 0 if there are no components.
+	pub fn condensation_diameter(&self) -> usize {
+		self.cached_depths().iter().copied().max().unwrap_or(0)
+	}
+
+	/// Computes the path of maximum total weight in the condensation graph.
+	///
+	/// The weight of each component is given by `weight(i, members)`, and
+	/// the weight of a path is the sum of the weights of its components.
+	/// Returns the weight of the heaviest path along with the indices of its
+	/// components, from first to last. Returns the default weight and an empty
+	/// path if there are no components.
+	///
+	/// ```
+	/// use scc_trait::Scc;
+	///
+	/// let graph = vec![vec![1, 2], vec![3], vec![3], vec![]];
+	/// let cost: [u32; 4] = [1, 5, 2, 1];
+	/// let components = graph.strongly_connected_components();
+	/// let (total, path) = components.critical_path(|_, c| c.iter().map(|&v| cost[v]).sum::<u32>());
+	/// assert_eq!(total, 7);
+	/// assert_eq!(components.get_by_index(path[1]), Some(&[1][..]));
+	/// ```
+	///
+	/// # Panics
+	///
+	/// Panics if the condensation graph is cyclic.
+	pub fn critical_path<W, F>(&self, weight: F) -> (W, Vec<usize>)
+	where
+		W: Copy + Default + PartialOrd + Add<Output = W>,
+		F: Fn(usize, &[V]) -> W,
+	{
+		let mut best = vec![W::default(); self.list.len()];
+		let mut next = vec![None; self.list.len()];
+
+		for &i in self.topological_order().iter().rev() {
+			let mut max: Option<usize> = None;
+			for &j in &self.successors[i] {
+				if j != i && max.is_none_or(|k| best[j] > best[k]) {
+					max = Some(j)
+				}
+			}
+
+			let w = weight(i, &self.list[i]);
+			best[i] = match max {
+				Some(j) => w + best[j],
+				None => w,
+			};
+			next[i] = max
+		}
+
+		let mut start: Option<usize> = None;
+		for i in 0..self.list.len() {
+			if start.is_none_or(|k| best[i] > best[k]) {
+				start = Some(i)
+			}
+		}
+
+		let Some(start) = start else {
+			return (W::default(), Vec::new());
+		};
+
+		let mut path = vec![start];
+		while let Some(j) = next[*path.last().unwrap()] {
+			path.push(j)
+		}
+
+		(best[start], path)
+	}
+
+	/// Returns the width of the condensation graph.
+	///
+	/// This is the size of the largest set of components that are pairwise
+	/// unreachable from each other (antichain), indicating how many
+	/// components can be processed in parallel. By Dilworth's theorem, it is
+	/// equal to the size of a minimum path cover of the transitive closure,
+	/// computed here using the Hopcroft-Karp bipartite matching algorithm.
+	///
+	/// This requires computing the
+	/// [`transitive_closure`](Self::transitive_closure) of the condensation
+	/// graph, stored in `len()²` bits.
+	pub fn condensation_width(&self) -> usize {
+		let closure = self.transitive_closure();
+		let n = self.list.len();
+
+		// Hopcroft-Karp matching between the components (left) and the
+		// components they reach (right).
+		let mut left: Vec<Option<usize>> = vec![None; n];
+		let mut right: Vec<Option<usize>> = vec![None; n];
+		let mut distance = vec![usize::MAX; n];
+		let mut cursor = vec![0; n];
+		let mut matching = 0;
+
+		loop {
+			// Layer the free left components and the ones reachable from them
+			// through alternating paths.
+			let mut queue = VecDeque::new();
+			for i in 0..n {
+				if left[i].is_none() {
+					distance[i] = 0;
+					queue.push_back(i)
+				} else {
+					distance[i] = usize::MAX
+				}
+			}
+
+			let mut found = false;
+			while let Some(i) = queue.pop_front() {
+				for j in closure.reachable_from(i).filter(|&j| j != i) {
+					match right[j] {
+						None => found = true,
+						Some(k) => {
+							if distance[k] == usize::MAX {
+								distance[k] = distance[i] + 1;
+								queue.push_back(k)
+							}
+						}
+					}
+				}
+			}
+
+			if !found {
+				break;
+			}
+
+			// Find vertex-disjoint shortest augmenting paths, using an
+			// explicit stack.
+			cursor.fill(0);
+			for root in 0..n {
+				if left[root].is_some() {
+					continue;
+				}
+
+				let mut stack = vec![root];
+				let mut via = Vec::new();
+				while let Some(&i) = stack.last() {
+					match closure.next_reachable(i, cursor[i]) {
+						Some(j) => {
+							cursor[i] = j + 1;
+							if j == i {
+								continue;
+							}
+
+							match right[j] {
+								None => {
+									// Augment along the path.
+									via.push(j);
+									for (&i, &j) in stack.iter().zip(&via) {
+										left[i] = Some(j);
+										right[j] = Some(i)
+									}
+
+									matching += 1;
+									break;
+								}
+								Some(k) => {
+									if distance[k] == distance[i] + 1 {
+										stack.push(k);
+										via.push(j)
+									}
+								}
+							}
+						}
+						None => {
+							distance[i] = usize::MAX;
+							stack.pop();
+							via.pop();
+						}
+					}
+				}
+			}
+		}
+
+		n - matching
+	}
+
+	/// Finds a cycle in the component with index `i`.
+	///
+	/// Returns a shortest cycle `[v0, ..., vn]` going through the first vertex
+	/// `v0` of the component, where each vertex is a predecessor of the next
+	/// one, and `vn` a predecessor of `v0`. Returns `None` if `i` is out of
+	/// bounds or if the component is not cyclic.
+	pub fn find_cycle<G>(&self, graph: &G, i: usize) -> Option<Vec<V>>
+	where
+		G: ?Sized + Scc<Vertex = V>,
+		V: Clone + Eq + Hash,
+		S: BuildHasher,
+	{
+		let start = self.list.get(i)?.first()?;
+		let mut parent: HashMap<V, V> = HashMap::new();
+		let mut queue = VecDeque::new();
+		queue.push_back(start.clone());
+
+		while let Some(u) = queue.pop_front() {
+			for w in graph.successors(u.clone()) {
+				if w == *start {
+					let mut cycle = vec![u.clone()];
+					let mut v = &u;
+					while let Some(p) = parent.get(v) {
+						cycle.push(p.clone());
+						v = p
+					}
 
-	/// Map from vertices to component index.
-	vertex_to_component: HashMap<V, usize>,
+					cycle.reverse();
+					return Some(cycle);
+				}
 
-	/// Component successors.
-	successors: Vec<HashSet<usize>>,
-}
+				if self.vertex_to_component.get(&w) == Some(&i) && !parent.contains_key(&w) {
+					parent.insert(w.clone(), u.clone());
+					queue.push_back(w)
+				}
+			}
+		}
 
-impl<V> Components<V> {
-	/// Returns the number of strongly connected components.
-	pub fn len(&self) -> usize {
-		self.list.len()
+		None
 	}
 
-	/// Checks if there are no components.
-	pub fn is_empty(&self) -> bool {
-		self.list.is_empty()
-	}
+	/// Extracts the subgraph induced by the component with index `i`.
+	///
+	/// The result is an adjacency list where vertex `k` is the `k`-th vertex of
+	/// the component, as returned by [`get_by_index`](Self::get_by_index).
+	/// Only the edges between vertices of the component are kept, including
+	/// self-loops. Successors are sorted and deduplicated. Returns `None` if
+	/// `i` is out of bounds.
+	///
+	/// ```
+	/// use scc_trait::Scc;
+	///
+	/// let graph = vec![vec![1], vec![0, 2], vec![]];
+	/// let components = graph.strongly_connected_components();
+	/// let i = components.vertex_component_index(&0).unwrap();
+	/// let subgraph = components.induced_subgraph(&graph, i).unwrap();
+	/// assert_eq!(subgraph.len(), 2);
+	/// assert_eq!(subgraph.strongly_connected_components().len(), 1);
+	/// ```
+	pub fn induced_subgraph<G>(&self, graph: &G, i: usize) -> Option<Vec<Vec<usize>>>
+	where
+		G: ?Sized + Scc<Vertex = V>,
+		V: Clone + Eq + Hash,
+		S: BuildHasher,
+	{
+		let members = self.list.get(i)?;
+		let index: HashMap<V, usize> = members
+			.iter()
+			.enumerate()
+			.map(|(k, v)| (v.clone(), k))
+			.collect();
 
-	/// Returns an iterator over the strongly connected components.
-	pub fn iter(&self) -> Iter<V> {
-		Iter(self.list.iter())
+		Some(
+			members
+				.iter()
+				.map(|v| {
+					let mut successors: Vec<usize> = graph
+						.successors(v.clone())
+						.into_iter()
+						.filter_map(|w| index.get(&w).copied())
+						.collect();
+					successors.sort_unstable();
+					successors.dedup();
+					successors
+				})
+				.collect(),
+		)
 	}
 
-	/// Returns the index of the given vertex's strongly connected component.
-	pub fn vertex_component_index(&self, v: &V) -> Option<usize>
+	/// Computes the period of the component with index `i`.
+	///
+	/// The period is the greatest common divisor of the lengths of the
+	/// cycles in the component. It is computed by assigning each vertex its
+	/// distance from the first vertex of the component, and taking the gcd
+	/// of `distance(u) + 1 - distance(v)` over every internal edge `u → v`.
+	/// Returns 0 if `i` is out of bounds or if the component is not cyclic,
+	/// which includes empty components.
+	///
+	/// ```
+	/// use scc_trait::Scc;
+	///
+	/// let graph = vec![vec![1], vec![2], vec![3], vec![0, 2]];
+	/// let components = graph.strongly_connected_components();
+	/// assert_eq!(components.period(&graph, 0), 2);
+	/// assert!(!components.is_aperiodic(&graph, 0));
+	/// ```
+	pub fn period<G>(&self, graph: &G, i: usize) -> usize
 	where
-		V: Eq + Hash,
+		G: ?Sized + Scc<Vertex = V>,
+		V: Clone + Eq + Hash,
+		S: BuildHasher,
 	{
-		self.vertex_to_component.get(v).cloned()
+		let Some(local) = self
+			.induced_subgraph(graph, i)
+			.filter(|local| !local.is_empty())
+		else {
+			return 0;
+		};
+
+		let mut distance = vec![None; local.len()];
+		distance[0] = Some(0usize);
+		let mut queue = VecDeque::new();
+		queue.push_back(0);
+
+		let mut period = 0;
+		while let Some(k) = queue.pop_front() {
+			let d = distance[k].unwrap();
+			for &l in &local[k] {
+				match distance[l] {
+					Some(e) => period = gcd(period, d + 1 - e),
+					None => {
+						distance[l] = Some(d + 1);
+						queue.push_back(l)
+					}
+				}
+			}
+		}
+
+		period
 	}
 
-	/// Returns the component with the given index `i`.
-	pub fn get_by_index(&self, i: usize) -> Option<&[V]> {
-		self.list.get(i).map(Vec::as_slice)
+	/// Checks if the component with index `i` is aperiodic, meaning its
+	/// [`period`](Self::period) is 1.
+	pub fn is_aperiodic<G>(&self, graph: &G, i: usize) -> bool
+	where
+		G: ?Sized + Scc<Vertex = V>,
+		V: Clone + Eq + Hash,
+		S: BuildHasher,
+	{
+		self.period(graph, i) == 1
 	}
 
-	/// Return the given vertex's strongly connected component.
-	pub fn get(&self, v: &V) -> Option<&[V]>
+	/// Enumerates the elementary cycles of the component with index `i`,
+	/// using Johnson's algorithm.
+	///
+	/// Each cycle `[v0, ..., vn]` is listed once, where each vertex is a
+	/// predecessor of the next one, and `vn` a predecessor of `v0`. At most
+	/// `limit` cycles are returned, since there can be exponentially many of
+	/// them. Returns no cycles if `i` is out of bounds.
+	///
+	/// ```
+	/// use scc_trait::Scc;
+	///
+	/// let graph = vec![vec![1, 2], vec![0, 2], vec![0]];
+	/// let components = graph.strongly_connected_components();
+	/// let cycles = components.elementary_cycles(&graph, 0, usize::MAX);
+	/// assert_eq!(cycles.len(), 3);
+	/// ```
+	pub fn elementary_cycles<G>(&self, graph: &G, i: usize, limit: usize) -> Vec<Vec<V>>
 	where
-		V: Eq + Hash,
+		G: ?Sized + Scc<Vertex = V>,
+		V: Clone + Eq + Hash,
+		S: BuildHasher,
 	{
-		self.get_by_index(self.vertex_component_index(v)?)
+		let Some(local) = self.induced_subgraph(graph, i) else {
+			return Vec::new();
+		};
+
+		let members = &self.list[i];
+		cycles::johnson(&local, limit)
+			.into_iter()
+			.map(|cycle| cycle.into_iter().map(|k| members[k].clone()).collect())
+			.collect()
 	}
 
-	pub fn successors(&self, i: usize) -> Option<impl '_ + Iterator<Item = usize>> {
-		self.successors.get(i).map(|s| s.iter().cloned())
+	/// Returns an iterator over the cyclic components, with an example cycle
+	/// for each of them.
+	///
+	/// Yields `(i, members, cycle)` for each cyclic component `i`, where
+	/// `cycle` is computed using [`find_cycle`](Self::find_cycle).
+	pub fn iter_cyclic<'a, G>(
+		&'a self,
+		graph: &'a G,
+	) -> impl 'a + Iterator<Item = (usize, &'a [V], Vec<V>)>
+	where
+		G: ?Sized + Scc<Vertex = V>,
+		V: Clone + Eq + Hash,
+		S: BuildHasher,
+	{
+		self.list.iter().enumerate().filter_map(|(i, members)| {
+			let cycle = self.find_cycle(graph, i)?;
+			Some((i, members.as_slice(), cycle))
+		})
 	}
 
-	pub fn is_cyclic(&self, i: usize) -> bool {
-		self.successors.get(i).unwrap().contains(&i)
+	/// Attaches a label to each component, computed from its index and
+	/// members.
+	pub fn with_labels<F: Fn(usize, &[V]) -> String>(&self, f: F) -> ComponentLabels {
+		ComponentLabels::new(
+			self.list
+				.iter()
+				.enumerate()
+				.map(|(i, component)| f(i, component))
+				.collect(),
+		)
 	}
 
-	fn remove_indirect_successors(&self, result: &mut HashSet<usize>, i: usize) {
-		for j in self.successors(i).unwrap() {
-			result.remove(&j);
-			self.remove_indirect_successors(result, j);
+	/// Computes the strongly connected components of the component with index
+	/// `i`, ignoring the edges in `cut`.
+	///
+	/// Only the edges between vertices of the component are considered. This
+	/// shows how the component would decompose if the `cut` edges were
+	/// removed. Returns empty components if `i` is out of bounds.
+	pub fn refine_component<G>(&self, graph: &G, i: usize, cut: &HashSet<(V, V)>) -> Components<V>
+	where
+		G: ?Sized + Scc<Vertex = V>,
+		V: Clone + Eq + Hash,
+		S: BuildHasher,
+	{
+		struct Refined<'a, G: ?Sized, V, S> {
+			graph: &'a G,
+			components: &'a Components<V, S>,
+			i: usize,
+			cut: &'a HashSet<(V, V)>,
 		}
-	}
 
-	pub fn direct_successors(&self, i: usize) -> Option<HashSet<usize>> {
-		let mut result: HashSet<_> = self.successors(i)?.collect();
+		impl<G: ?Sized + Scc, S: BuildHasher> Scc for Refined<'_, G, G::Vertex, S> {
+			type Vertex = G::Vertex;
 
-		for j in self.successors(i).unwrap() {
-			self.remove_indirect_successors(&mut result, j);
+			fn vertices(&self) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+				self.components.list[self.i].iter().cloned()
+			}
+
+			fn successors(&self, v: Self::Vertex) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+				self.graph
+					.successors(v.clone())
+					.into_iter()
+					.filter(move |w| {
+						self.components.vertex_to_component.get(w) == Some(&self.i)
+							&& !self.cut.contains(&(v.clone(), w.clone()))
+					})
+			}
 		}
 
-		Some(result)
+		if i >= self.list.len() {
+			return Components::empty();
+		}
+
+		Refined {
+			graph,
+			components: self,
+			i,
+			cut,
+		}
+		.strongly_connected_components()
 	}
 
-	/// Returns the depth of each component.
+	/// Computes the immediate dominator of each component in the condensation
+	/// graph, starting from `root`.
 	///
-	/// The depth of a component is the maximum of the depth of its predecessors
-	/// plus 1. A component with no predecessors has depth 0.
-	pub fn depths(&self) -> Vec<usize> {
-		let mut depth = vec![0; self.list.len()];
-		let mut stack: Vec<_> = depth.iter().cloned().enumerate().collect();
-
-		while let Some((i, new_depth)) = stack.pop() {
-			if depth[i] == 0 || new_depth > depth[i] {
-				depth[i] = new_depth;
-				for c in self.successors(i).unwrap() {
-					if c != i {
-						stack.push((c, new_depth + 1))
-					}
+	/// A component `d` dominates `i` if every path from `root` to `i` goes
+	/// through `d`. The immediate dominator of `i` is its closest strict
+	/// dominator. The result maps each component index to its immediate
+	/// dominator, or `None` for `root` itself and components unreachable from
+	/// `root`.
+	///
+	/// # Panics
+	///
+	/// Panics if `root` is out of bounds.
+	pub fn dominators(&self, root: usize) -> Vec<Option<usize>> {
+		let mut reachable = vec![false; self.list.len()];
+		reachable[root] = true;
+		let mut stack = vec![root];
+		while let Some(i) = stack.pop() {
+			for &j in &self.successors[i] {
+				if !reachable[j] {
+					reachable[j] = true;
+					stack.push(j)
 				}
 			}
 		}
 
-		depth
-	}
+		let predecessors = self.cached_predecessors();
+		let mut idom = vec![None; self.list.len()];
+		let mut depth = vec![0; self.list.len()];
 
-	pub fn predecessors(&self) -> Vec<HashSet<usize>> {
-		let mut predecessors = Vec::new();
-		predecessors.resize_with(self.list.len(), HashSet::default);
+		// Since the condensation is acyclic, processing components in
+		// topological order ensures the dominators of all predecessors are
+		// known.
+		for i in self.topological_order() {
+			if i == root || !reachable[i] {
+				continue;
+			}
 
-		for (i, successors) in self.successors.iter().enumerate() {
-			for &j in successors {
-				predecessors[j].insert(i);
+			let mut dominator: Option<usize> = None;
+			for &p in &predecessors[i] {
+				if p != i && reachable[p] {
+					dominator = Some(match dominator {
+						None => p,
+						Some(mut d) => {
+							// Common ancestor of `d` and `p` in the dominator tree.
+							let mut p = p;
+							while d != p {
+								if depth[d] >= depth[p] {
+									d = idom[d].unwrap()
+								} else {
+									p = idom[p].unwrap()
+								}
+							}
+
+							d
+						}
+					})
+				}
 			}
+
+			let dominator = dominator.unwrap();
+			idom[i] = Some(dominator);
+			depth[i] = depth[dominator] + 1
 		}
 
-		predecessors
+		idom
 	}
 
-	/// Order components by depth.
+	/// Returns the set of components dominated by `i`, starting from `root`.
 	///
-	/// The depth of a component is the maximum of the depth of its predecessors
-	/// plus 1. A component with no predecessors has depth 0.
-	pub fn order_by_depth(&self) -> Vec<usize> {
-		let depth = self.depths();
-		let mut ordered_components: Vec<_> = (0..self.list.len()).collect();
-		ordered_components.sort_unstable_by_key(|i| depth[*i]);
-		ordered_components
+	/// This is every component that can only be reached from `root` by going
+	/// through `i`, including `i` itself. It is empty if `i` is not reachable
+	/// from `root`. See [`dominators`](Self::dominators).
+	///
+	/// # Panics
+	///
+	/// Panics if `root` is out of bounds.
+	pub fn dominated_by(&self, root: usize, i: usize) -> HashSet<usize> {
+		let idom = self.dominators(root);
+
+		let mut children = vec![Vec::new(); self.list.len()];
+		for (j, d) in idom.iter().enumerate() {
+			if let Some(d) = d {
+				children[*d].push(j)
+			}
+		}
+
+		let mut result = HashSet::new();
+		if i == root || idom.get(i).is_some_and(Option::is_some) {
+			result.insert(i);
+			let mut stack = vec![i];
+			while let Some(j) = stack.pop() {
+				for &k in &children[j] {
+					result.insert(k);
+					stack.push(k)
+				}
+			}
+		}
+
+		result
 	}
 }
 
+/// Structural summary of strongly connected components.
+///
+/// See [`Components::summary`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ComponentsSummary {
+	/// Number of components.
+	pub total_components: usize,
+
+	/// Number of trivial components, made of a single vertex without
+	/// self-loop.
+	pub trivial_components: usize,
+
+	/// Number of cyclic components.
+	pub cyclic_components: usize,
+
+	/// Number of vertices in the largest component, 0 if there are no
+	/// components.
+	pub largest_component_size: usize,
+
+	/// Maximum component depth in the condensation graph, 0 if there are no
+	/// components.
+	pub condensation_depth: usize,
+
+	/// Number of edges between distinct components.
+	pub condensation_edges: usize,
+}
+
 pub struct Iter<'a, V>(std::slice::Iter<'a, Vec<V>>);
 
 impl<'a, V> Iterator for Iter<'a, V> {
@@ -216,7 +2485,13 @@ impl<'a, V> DoubleEndedIterator for Iter<'a, V> {
 	}
 }
 
-impl<'a, V> IntoIterator for &'a Components<V> {
+impl<V, S: Default> Default for Components<V, S> {
+	fn default() -> Self {
+		Self::with_hasher(S::default())
+	}
+}
+
+impl<'a, V, S> IntoIterator for &'a Components<V, S> {
 	type Item = &'a [V];
 	type IntoIter = Iter<'a, V>;
 
@@ -247,7 +2522,68 @@ pub fn depths(predecessors: &[HashSet<usize>]) -> Vec<usize> {
 	depth
 }
 
-impl Scc for Vec<HashSet<usize>> {
+/// Returns the greatest common divisor of `a` and `b`.
+fn gcd(mut a: usize, mut b: usize) -> usize {
+	while b != 0 {
+		(a, b) = (b, a % b)
+	}
+
+	a
+}
+
+/// Adjacency list, where `C` is any successor container viewable as a slice
+/// (`Vec`, `SmallVec`, `ArrayVec`, `&[usize]`, arrays, etc.).
+///
+/// Successor sets are not slices: a graph whose successors are stored in a
+/// `HashSet` or `BTreeSet` can use a [`HashMap`] or [`BTreeMap`] adjacency
+/// map instead.
+///
+/// ```
+/// use scc_trait::Scc;
+///
+/// let successors: [&[usize]; 3] = [&[1], &[0], &[]];
+/// let graph = successors.to_vec();
+/// assert_eq!(graph.strongly_connected_components().len(), 2);
+/// ```
+impl<C: AsRef<[usize]>> Scc for Vec<C> {
+	type Vertex = usize;
+
+	fn vertices(&self) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+		0..self.len()
+	}
+
+	fn successors(&self, v: Self::Vertex) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+		self[v].as_ref().iter().copied()
+	}
+
+	fn vertex_count_hint(&self) -> Option<usize> {
+		Some(self.len())
+	}
+
+	fn strongly_connected_components(&self) -> Components<Self::Vertex> {
+		dense::scc(self)
+	}
+}
+
+impl<C: AsRef<[usize]>> DenseScc for Vec<C> {
+	fn vertex_count(&self) -> usize {
+		self.len()
+	}
+}
+
+/// Adjacency list slice, where `C` is any successor container viewable as a
+/// slice, including borrowed successor slices (`[&[usize]]`).
+///
+/// ```
+/// use scc_trait::Scc;
+///
+/// let list: &[Vec<usize>] = &[vec![1], vec![0], vec![]];
+/// assert_eq!(list.strongly_connected_components().len(), 2);
+///
+/// let slices: &[&[usize]] = &[&[1], &[0], &[]];
+/// assert_eq!(slices.strongly_connected_components().len(), 2);
+/// ```
+impl<C: AsRef<[usize]>> Scc for [C] {
 	type Vertex = usize;
 
 	fn vertices(&self) -> impl '_ + IntoIterator<Item = Self::Vertex> {
@@ -255,18 +2591,261 @@ impl Scc for Vec<HashSet<usize>> {
 	}
 
 	fn successors(&self, v: Self::Vertex) -> impl '_ + IntoIterator<Item = Self::Vertex> {
-		self[v].iter().copied()
+		self[v].as_ref().iter().copied()
+	}
+
+	fn vertex_count_hint(&self) -> Option<usize> {
+		Some(self.len())
+	}
+
+	fn strongly_connected_components(&self) -> Components<Self::Vertex> {
+		dense::scc(self)
+	}
+}
+
+impl<C: AsRef<[usize]>> DenseScc for [C] {
+	fn vertex_count(&self) -> usize {
+		self.len()
+	}
+}
+
+/// Adjacency map, where `C` is any successor container (`HashSet`, `Vec`,
+/// `BTreeSet`, `SmallVec`, `ArrayVec`, etc.).
+///
+/// Vertices only appearing as successors, without an entry of their own, are
+/// treated as vertices without successors.
+///
+/// ```
+/// use std::collections::HashMap;
+/// use scc_trait::Scc;
+///
+/// let dependencies = HashMap::from([
+///   ("app", vec!["lib", "log"]),
+///   ("lib", vec!["log"]),
+///   ("log", vec!["lib"]),
+/// ]);
+///
+/// let components = dependencies.strongly_connected_components();
+/// assert!(components.same_component(&"lib", &"log"));
+/// assert!(!components.same_component(&"app", &"lib"));
+/// ```
+impl<T: Clone + Eq + Hash, C> Scc for HashMap<T, C>
+where
+	for<'a> &'a C: IntoIterator<Item = &'a T>,
+{
+	type Vertex = T;
+
+	fn vertices(&self) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+		self.keys().cloned()
+	}
+
+	fn successors(&self, v: Self::Vertex) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+		self.get(&v).into_iter().flatten().cloned()
+	}
+
+	fn vertex_count_hint(&self) -> Option<usize> {
+		Some(self.len())
 	}
 }
 
-impl<T: Copy + Eq + Hash> Scc for HashMap<T, HashSet<T>> {
+/// Ordered adjacency map, where `C` is any successor container (`BTreeSet`,
+/// `Vec`, etc.).
+///
+/// Vertices only appearing as successors, without an entry of their own, are
+/// treated as vertices without successors.
+///
+/// Vertices are enumerated in increasing order, so the computed components,
+/// and the order of vertices in each component, are the same across runs as
+/// long as the successor containers are themselves ordered.
+///
+/// ```
+/// use std::collections::{BTreeMap, BTreeSet};
+/// use scc_trait::Scc;
+///
+/// let graph = BTreeMap::from([
+///   (1, BTreeSet::from([2])),
+///   (2, BTreeSet::from([1])),
+///   (3, BTreeSet::from([1])),
+/// ]);
+///
+/// let components = graph.strongly_connected_components();
+/// let list: Vec<&[u32]> = components.iter().collect();
+/// assert_eq!(list, [&[2, 1][..], &[3]]);
+/// ```
+impl<T: Clone + Ord + Hash, C> Scc for BTreeMap<T, C>
+where
+	for<'a> &'a C: IntoIterator<Item = &'a T>,
+{
 	type Vertex = T;
 
 	fn vertices(&self) -> impl '_ + IntoIterator<Item = Self::Vertex> {
-		self.keys().copied()
+		self.keys().cloned()
 	}
 
 	fn successors(&self, v: Self::Vertex) -> impl '_ + IntoIterator<Item = Self::Vertex> {
-		self[&v].iter().copied()
+		self.get(&v).into_iter().flatten().cloned()
+	}
+
+	fn vertex_count_hint(&self) -> Option<usize> {
+		Some(self.len())
 	}
 }
+
+/// Forwards the [`Scc`], [`SccBidirectional`] and [`DenseScc`]
+/// implementations of a graph to a pointer type.
+///
+/// Every overridable method is forwarded, so that the specialized
+/// implementations of the graph are also used through the pointer, except
+/// the ones returning a view of `Self` and the parallel algorithm, whose
+/// `Self: Sync` bound does not carry over to the graph.
+macro_rules! forward_scc {
+	($($ty:ty),*) => {
+		$(
+			impl<G: ?Sized + Scc> Scc for $ty {
+				type Vertex = G::Vertex;
+
+				fn vertices(&self) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+					G::vertices(self)
+				}
+
+				fn successors(&self, v: Self::Vertex) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+					G::successors(self, v)
+				}
+
+				fn vertex_count_hint(&self) -> Option<usize> {
+					G::vertex_count_hint(self)
+				}
+
+				fn edge_count_hint(&self) -> Option<usize> {
+					G::edge_count_hint(self)
+				}
+
+				fn strongly_connected_components(&self) -> Components<Self::Vertex> {
+					G::strongly_connected_components(self)
+				}
+
+				fn strongly_connected_components_with_hasher<S: BuildHasher + Clone>(
+					&self,
+					hasher: S,
+				) -> Components<Self::Vertex, S> {
+					G::strongly_connected_components_with_hasher(self, hasher)
+				}
+
+				fn strongly_connected_components_compact<Ix: IndexType>(
+					&self,
+				) -> CompactComponents<Self::Vertex, Ix> {
+					G::strongly_connected_components_compact(self)
+				}
+
+				fn strongly_connected_components_from(
+					&self,
+					roots: impl IntoIterator<Item = Self::Vertex>,
+				) -> Components<Self::Vertex> {
+					G::strongly_connected_components_from(self, roots)
+				}
+
+				fn scc_iter(&self) -> impl '_ + Iterator<Item = Vec<Self::Vertex>> {
+					G::scc_iter(self)
+				}
+
+				fn strongly_connected_components_pearce(&self) -> Components<Self::Vertex> {
+					G::strongly_connected_components_pearce(self)
+				}
+
+				fn strongly_connected_components_gabow(&self) -> Components<Self::Vertex> {
+					G::strongly_connected_components_gabow(self)
+				}
+
+				fn reachable_set(&self, from: Self::Vertex) -> HashSet<Self::Vertex> {
+					G::reachable_set(self, from)
+				}
+
+				fn component_of(&self, v: Self::Vertex) -> Vec<Self::Vertex> {
+					G::component_of(self, v)
+				}
+
+				fn strongly_connected_component_of(&self, v: Self::Vertex) -> Vec<Self::Vertex> {
+					G::strongly_connected_component_of(self, v)
+				}
+
+				fn has_path(&self, from: Self::Vertex, to: Self::Vertex) -> bool {
+					G::has_path(self, from, to)
+				}
+
+				fn topological_sort(&self) -> Result<Vec<Self::Vertex>, Vec<Self::Vertex>> {
+					G::topological_sort(self)
+				}
+
+				fn has_cycle(&self) -> bool {
+					G::has_cycle(self)
+				}
+
+				fn is_dag(&self) -> bool {
+					G::is_dag(self)
+				}
+
+				fn is_strongly_connected(&self) -> bool {
+					G::is_strongly_connected(self)
+				}
+
+				fn find_cycle(&self) -> Option<Vec<Self::Vertex>> {
+					G::find_cycle(self)
+				}
+
+				fn strongly_connected_components_resumable(&self) -> SccProgress<Self::Vertex> {
+					G::strongly_connected_components_resumable(self)
+				}
+
+				fn resume(
+					&self,
+					progress: &mut SccProgress<Self::Vertex>,
+					max_steps: usize,
+				) -> Option<Components<Self::Vertex>> {
+					G::resume(self, progress, max_steps)
+				}
+
+				fn connected_component_count(&self) -> usize {
+					G::connected_component_count(self)
+				}
+
+				fn weakly_connected_components(&self) -> Components<Self::Vertex> {
+					G::weakly_connected_components(self)
+				}
+
+				fn for_each_edge<F: FnMut(Self::Vertex, Self::Vertex)>(&self, f: F) {
+					G::for_each_edge(self, f)
+				}
+
+				fn edge_count(&self) -> usize {
+					G::edge_count(self)
+				}
+			}
+
+			impl<G: ?Sized + SccBidirectional> SccBidirectional for $ty {
+				fn predecessors(&self, v: Self::Vertex) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+					G::predecessors(self, v)
+				}
+
+				fn strongly_connected_components_kosaraju(&self) -> Components<Self::Vertex> {
+					G::strongly_connected_components_kosaraju(self)
+				}
+
+				fn neighbors_undirected(&self, v: Self::Vertex) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+					G::neighbors_undirected(self, v)
+				}
+			}
+
+			impl<G: ?Sized + DenseScc> DenseScc for $ty {
+				fn vertex_count(&self) -> usize {
+					G::vertex_count(self)
+				}
+
+				fn strongly_connected_components_dense(&self) -> Components<usize> {
+					G::strongly_connected_components_dense(self)
+				}
+			}
+		)*
+	};
+}
+
+forward_scc!(&G, &mut G, Box<G>, Rc<G>, Arc<G>);
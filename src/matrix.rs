@@ -0,0 +1,230 @@
+use super::{dense, Components, DenseScc, Scc};
+
+/// Number of bits in a bitset block.
+const BLOCK_BITS: usize = u64::BITS as usize;
+
+/// Graph given by a dense boolean adjacency matrix.
+///
+/// Vertices are the row indices, and there is an edge from `u` to `v` if
+/// `rows[u][v]` is `true`. Any row type dereferencing to a boolean slice is
+/// accepted, such as `Vec<bool>` or `[bool; N]`.
+///
+/// Matrices such as `Vec<Vec<bool>>` or `&[Vec<bool>]` do not implement
+/// [`Scc`] themselves, since those implementations would overlap with the
+/// adjacency list implementations for `Vec<C>` and `[C]`. They are wrapped
+/// in this type instead.
+///
+/// ```
+/// use scc_trait::{AdjacencyMatrix, Scc};
+///
+/// let matrix = vec![
+///   vec![false, true, false],
+///   vec![true, false, false],
+///   vec![false, true, false],
+/// ];
+///
+/// let components = AdjacencyMatrix::new(&matrix).strongly_connected_components();
+/// assert_eq!(components.len(), 2);
+/// ```
+pub struct AdjacencyMatrix<'a, R> {
+	rows: &'a [R],
+}
+
+impl<'a, R: AsRef<[bool]>> AdjacencyMatrix<'a, R> {
+	/// Creates a graph from the rows of the given adjacency matrix.
+	///
+	/// Rows may be longer than the number of rows, as long as the extra
+	/// entries are `false`.
+	///
+	/// # Panics
+	///
+	/// Panics if a row has a `true` entry at an index greater than or equal to
+	/// the number of rows, since it would be an edge to a non-existent vertex.
+	pub fn new(rows: &'a [R]) -> Self {
+		let len = rows.len();
+		assert!(
+			rows.iter()
+				.all(|row| row.as_ref().iter().skip(len).all(|&edge| !edge)),
+			"vertex out of bounds"
+		);
+
+		Self { rows }
+	}
+}
+
+impl<R> Clone for AdjacencyMatrix<'_, R> {
+	fn clone(&self) -> Self {
+		*self
+	}
+}
+
+impl<R> Copy for AdjacencyMatrix<'_, R> {}
+
+impl<R: AsRef<[bool]>> Scc for AdjacencyMatrix<'_, R> {
+	type Vertex = usize;
+
+	fn vertices(&self) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+		0..self.rows.len()
+	}
+
+	fn successors(&self, v: Self::Vertex) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+		self.rows[v]
+			.as_ref()
+			.iter()
+			.enumerate()
+			.filter(|(_, &edge)| edge)
+			.map(|(w, _)| w)
+	}
+
+	fn vertex_count_hint(&self) -> Option<usize> {
+		Some(self.rows.len())
+	}
+
+	fn strongly_connected_components(&self) -> Components<usize> {
+		dense::scc(self)
+	}
+}
+
+impl<R: AsRef<[bool]>> DenseScc for AdjacencyMatrix<'_, R> {
+	fn vertex_count(&self) -> usize {
+		self.rows.len()
+	}
+}
+
+/// Bit-packed square adjacency matrix.
+///
+/// Each row is stored as a packed bitset, using one bit per potential edge.
+/// This is eight times more compact than a `Vec<Vec<bool>>`, and successors
+/// are found by scanning whole blocks at once.
+///
+/// ```
+/// use scc_trait::{BitMatrix, Scc};
+///
+/// let mut matrix = BitMatrix::new(3);
+/// matrix.insert(0, 1);
+/// matrix.insert(1, 0);
+/// matrix.insert(2, 1);
+///
+/// assert_eq!(matrix.strongly_connected_components().len(), 2);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitMatrix {
+	/// Number of vertices.
+	len: usize,
+
+	/// Number of blocks per row.
+	stride: usize,
+
+	/// Bitset rows, concatenated.
+	blocks: Vec<u64>,
+}
+
+impl BitMatrix {
+	/// Creates a matrix of `len` vertices without edges.
+	pub fn new(len: usize) -> Self {
+		let stride = len.div_ceil(BLOCK_BITS);
+		Self {
+			len,
+			stride,
+			blocks: vec![0; len * stride],
+		}
+	}
+
+	/// Creates a bit-packed copy of the given boolean adjacency matrix.
+	///
+	/// # Panics
+	///
+	/// Panics if a row has a `true` entry at an index greater than or equal to
+	/// the number of rows. Extra `false` entries are ignored.
+	pub fn from_rows<R: AsRef<[bool]>>(rows: &[R]) -> Self {
+		let mut result = Self::new(rows.len());
+		for (u, row) in rows.iter().enumerate() {
+			for (v, &edge) in row.as_ref().iter().enumerate() {
+				if edge {
+					result.insert(u, v)
+				}
+			}
+		}
+
+		result
+	}
+
+	/// Returns the number of vertices.
+	pub fn len(&self) -> usize {
+		self.len
+	}
+
+	/// Checks if the matrix has no vertices.
+	pub fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+
+	/// Checks if there is an edge from `u` to `v`.
+	///
+	/// Returns `false` if either vertex is out of bounds.
+	pub fn contains(&self, u: usize, v: usize) -> bool {
+		u < self.len
+			&& v < self.len
+			&& self.blocks[u * self.stride + v / BLOCK_BITS] & (1 << (v % BLOCK_BITS)) != 0
+	}
+
+	/// Adds an edge from `u` to `v`.
+	///
+	/// # Panics
+	///
+	/// Panics if either vertex is out of bounds.
+	pub fn insert(&mut self, u: usize, v: usize) {
+		assert!(u < self.len && v < self.len, "vertex out of bounds");
+		self.blocks[u * self.stride + v / BLOCK_BITS] |= 1 << (v % BLOCK_BITS)
+	}
+
+	/// Removes the edge from `u` to `v`.
+	///
+	/// # Panics
+	///
+	/// Panics if either vertex is out of bounds.
+	pub fn remove(&mut self, u: usize, v: usize) {
+		assert!(u < self.len && v < self.len, "vertex out of bounds");
+		self.blocks[u * self.stride + v / BLOCK_BITS] &= !(1 << (v % BLOCK_BITS))
+	}
+}
+
+impl Scc for BitMatrix {
+	type Vertex = usize;
+
+	fn vertices(&self) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+		0..self.len
+	}
+
+	fn successors(&self, v: Self::Vertex) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+		self.blocks[v * self.stride..(v + 1) * self.stride]
+			.iter()
+			.enumerate()
+			.flat_map(|(k, &block)| {
+				let mut block = block;
+				std::iter::from_fn(move || {
+					if block == 0 {
+						None
+					} else {
+						let b = block.trailing_zeros() as usize;
+						block &= block - 1;
+						Some(k * BLOCK_BITS + b)
+					}
+				})
+			})
+	}
+
+	fn vertex_count_hint(&self) -> Option<usize> {
+		Some(self.len)
+	}
+
+	fn strongly_connected_components(&self) -> Components<usize> {
+		dense::scc(self)
+	}
+}
+
+impl DenseScc for BitMatrix {
+	fn vertex_count(&self) -> usize {
+		self.len
+	}
+}
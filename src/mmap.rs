@@ -0,0 +1,116 @@
+use std::{fs::File, io, path::Path};
+
+use memmap2::Mmap;
+
+use super::Scc;
+
+/// Compressed sparse row graph stored in memory-mapped files.
+///
+/// The graph is made of two arrays of native-endian `u32` values:
+/// - `offsets`, of length `n + 1` where `n` is the number of vertices, such
+///   that the successors of vertex `v` are stored in
+///   `targets[offsets[v]..offsets[v + 1]]`;
+/// - `targets`, the concatenation of all successor lists.
+///
+/// Successors are read directly from the mapped files, without copy, which
+/// allows computing the strongly connected components of graphs larger than
+/// the available memory.
+pub struct MmapCsrGraph {
+	offsets: Mmap,
+	targets: Mmap,
+}
+
+impl MmapCsrGraph {
+	/// Creates a new graph from the given `offsets` and `targets` memory maps.
+	///
+	/// Returns an [`io::ErrorKind::InvalidData`] error if the maps do not
+	/// describe a valid graph.
+	pub fn from_mmaps(offsets: Mmap, targets: Mmap) -> io::Result<Self> {
+		let graph = Self { offsets, targets };
+
+		if !graph.offsets.len().is_multiple_of(4) || !graph.targets.len().is_multiple_of(4) {
+			return Err(invalid_data("length is not a multiple of 4"));
+		}
+
+		let offsets = graph.offsets();
+		if offsets.is_empty() {
+			return Err(invalid_data("missing offsets"));
+		}
+
+		if offsets.windows(2).any(|w| w[0] > w[1])
+			|| offsets[offsets.len() - 1] as usize > graph.targets().len()
+		{
+			return Err(invalid_data("invalid offsets"));
+		}
+
+		Ok(graph)
+	}
+
+	/// Maps the given `offsets` and `targets` files, and creates a new graph
+	/// from them.
+	///
+	/// # Safety
+	///
+	/// The files must not be modified, by this process or another, while they
+	/// are mapped. See [`Mmap::map`].
+	pub unsafe fn open(offsets: impl AsRef<Path>, targets: impl AsRef<Path>) -> io::Result<Self> {
+		let offsets = Mmap::map(&File::open(offsets)?)?;
+		let targets = Mmap::map(&File::open(targets)?)?;
+		Self::from_mmaps(offsets, targets)
+	}
+
+	/// Returns the number of vertices.
+	pub fn vertex_count(&self) -> usize {
+		self.offsets().len() - 1
+	}
+
+	fn offsets(&self) -> &[u32] {
+		as_u32_slice(&self.offsets)
+	}
+
+	fn targets(&self) -> &[u32] {
+		as_u32_slice(&self.targets)
+	}
+}
+
+fn invalid_data(message: &str) -> io::Error {
+	io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+fn as_u32_slice(bytes: &[u8]) -> &[u32] {
+	if bytes.is_empty() {
+		return &[];
+	}
+
+	assert_eq!(bytes.as_ptr().align_offset(std::mem::align_of::<u32>()), 0);
+	// SAFETY: memory maps are page-aligned, their length has been checked to
+	// be a multiple of 4, and any bit pattern is a valid `u32`.
+	unsafe { std::slice::from_raw_parts(bytes.as_ptr() as *const u32, bytes.len() / 4) }
+}
+
+impl Scc for MmapCsrGraph {
+	type Vertex = u32;
+
+	fn vertices(&self) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+		0..self.vertex_count() as u32
+	}
+
+	fn successors(&self, v: Self::Vertex) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+		let offsets = self.offsets();
+		let v = v as usize;
+		let successors = match (offsets.get(v), offsets.get(v + 1)) {
+			(Some(&start), Some(&end)) => &self.targets()[start as usize..end as usize],
+			_ => &[],
+		};
+
+		successors.iter().copied()
+	}
+
+	fn vertex_count_hint(&self) -> Option<usize> {
+		Some(self.vertex_count())
+	}
+
+	fn edge_count_hint(&self) -> Option<usize> {
+		Some(self.targets().len())
+	}
+}
@@ -0,0 +1,310 @@
+//! Parallel strongly connected components computation, using the
+//! Forward-Backward algorithm with trimming.
+//!
+//! See "On identifying strongly connected components in parallel" by Lisa K.
+//! Fleischer, Bruce Hendrickson and Ali Pınar (2000).
+use std::{
+	collections::{hash_map::Entry, HashMap, HashSet},
+	sync::{
+		atomic::{AtomicUsize, Ordering},
+		Mutex,
+	},
+};
+
+use rayon::prelude::*;
+
+use super::{Components, Scc};
+
+/// Label of vertices already assigned to a component.
+const DONE: usize = usize::MAX;
+
+/// Dense representation of the graph, shared between tasks.
+struct Context {
+	successors: Vec<Vec<usize>>,
+	predecessors: Vec<Vec<usize>>,
+
+	/// Label of the vertex subset each vertex belongs to.
+	///
+	/// Subsets are disjoint, and processed by independent tasks.
+	labels: Vec<AtomicUsize>,
+
+	/// Next unused label.
+	next_label: AtomicUsize,
+
+	/// Per-vertex degree counters, used for trimming.
+	in_degree: Vec<AtomicUsize>,
+	out_degree: Vec<AtomicUsize>,
+
+	/// Components found so far.
+	components: Mutex<Vec<Vec<usize>>>,
+}
+
+impl Context {
+	fn label(&self, v: usize) -> usize {
+		self.labels[v].load(Ordering::Relaxed)
+	}
+
+	fn set_label(&self, v: usize, label: usize) {
+		self.labels[v].store(label, Ordering::Relaxed)
+	}
+
+	/// Changes the label of `v` to `to` if it is `from`.
+	fn relabel(&self, v: usize, from: usize, to: usize) -> bool {
+		self.labels[v]
+			.compare_exchange(from, to, Ordering::Relaxed, Ordering::Relaxed)
+			.is_ok()
+	}
+
+	fn new_label(&self) -> usize {
+		self.next_label.fetch_add(1, Ordering::Relaxed)
+	}
+
+	/// Counts the neighbors of `v` (other than `v`) in the subset `label`.
+	fn degree(&self, neighbors: &[usize], v: usize, label: usize) -> usize {
+		neighbors
+			.iter()
+			.filter(|&&w| w != v && self.label(w) == label)
+			.count()
+	}
+
+	/// Removes the vertices of the subset `label` that have no predecessor or
+	/// no successor in the subset, since they are their own strongly connected
+	/// component. This is repeated until no vertex can be removed.
+	///
+	/// Returns the remaining vertices.
+	fn trim(&self, subset: Vec<usize>, label: usize) -> Vec<usize> {
+		subset.par_iter().for_each(|&v| {
+			let in_degree = self.degree(&self.predecessors[v], v, label);
+			let out_degree = self.degree(&self.successors[v], v, label);
+			self.in_degree[v].store(in_degree, Ordering::Relaxed);
+			self.out_degree[v].store(out_degree, Ordering::Relaxed);
+		});
+
+		let mut queue: Vec<usize> = subset
+			.iter()
+			.copied()
+			.filter(|&v| {
+				self.in_degree[v].load(Ordering::Relaxed) == 0
+					|| self.out_degree[v].load(Ordering::Relaxed) == 0
+			})
+			.collect();
+
+		let mut trimmed = Vec::new();
+		while let Some(v) = queue.pop() {
+			if self.label(v) != label {
+				continue;
+			}
+
+			self.set_label(v, DONE);
+			trimmed.push(vec![v]);
+
+			for &w in &self.successors[v] {
+				if w != v
+					&& self.label(w) == label
+					&& self.in_degree[w].fetch_sub(1, Ordering::Relaxed) == 1
+				{
+					queue.push(w)
+				}
+			}
+
+			for &u in &self.predecessors[v] {
+				if u != v
+					&& self.label(u) == label
+					&& self.out_degree[u].fetch_sub(1, Ordering::Relaxed) == 1
+				{
+					queue.push(u)
+				}
+			}
+		}
+
+		if !trimmed.is_empty() {
+			self.components.lock().unwrap().extend(trimmed);
+		}
+
+		subset
+			.into_par_iter()
+			.filter(|&v| self.label(v) == label)
+			.collect()
+	}
+
+	/// Parallel breadth-first search from `start`, following `neighbors` and
+	/// only visiting the vertices for which `claim` returns `true`.
+	fn search<'a>(
+		&'a self,
+		start: usize,
+		neighbors: impl Fn(usize) -> &'a [usize] + Sync,
+		claim: impl Fn(usize) -> bool + Sync,
+	) {
+		let mut frontier = vec![start];
+		while !frontier.is_empty() {
+			frontier = frontier
+				.par_iter()
+				.flat_map_iter(|&v| neighbors(v).iter().copied().filter(|&w| claim(w)))
+				.collect()
+		}
+	}
+
+	/// Finds the strongly connected components of the subset `label`, spawning
+	/// new tasks for the sub-problems.
+	fn process<'s>(&'s self, scope: &rayon::Scope<'s>, subset: Vec<usize>, label: usize) {
+		let subset = self.trim(subset, label);
+		let Some(&pivot) = subset.first() else {
+			return;
+		};
+
+		// Forward search: vertices reachable from the pivot.
+		let forward = self.new_label();
+		self.set_label(pivot, forward);
+		self.search(
+			pivot,
+			|v| &self.successors[v],
+			|w| self.relabel(w, label, forward),
+		);
+
+		// Backward search: vertices reaching the pivot. Those also reachable
+		// from the pivot form its component.
+		let component = self.new_label();
+		let backward = self.new_label();
+		self.set_label(pivot, component);
+		self.search(
+			pivot,
+			|v| &self.predecessors[v],
+			|u| self.relabel(u, forward, component) || self.relabel(u, label, backward),
+		);
+
+		let mut pivot_component = Vec::new();
+		let mut forward_subset = Vec::new();
+		let mut backward_subset = Vec::new();
+		let mut remaining_subset = Vec::new();
+		for v in subset {
+			match self.label(v) {
+				l if l == component => pivot_component.push(v),
+				l if l == forward => forward_subset.push(v),
+				l if l == backward => backward_subset.push(v),
+				_ => remaining_subset.push(v),
+			}
+		}
+
+		for &v in &pivot_component {
+			self.set_label(v, DONE)
+		}
+
+		self.components.lock().unwrap().push(pivot_component);
+
+		for (subset, label) in [
+			(forward_subset, forward),
+			(backward_subset, backward),
+			(remaining_subset, label),
+		] {
+			if !subset.is_empty() {
+				scope.spawn(move |scope| self.process(scope, subset, label))
+			}
+		}
+	}
+}
+
+pub fn scc<G>(graph: &G) -> Components<G::Vertex>
+where
+	G: ?Sized + Scc + Sync,
+	G::Vertex: Send + Sync,
+{
+	// Assign a dense index to every vertex, including vertices only reachable
+	// as successors.
+	let mut index: HashMap<G::Vertex, usize> = HashMap::new();
+	let mut vertices = Vec::new();
+	for v in graph.vertices() {
+		if let Entry::Vacant(entry) = index.entry(v.clone()) {
+			entry.insert(vertices.len());
+			vertices.push(v)
+		}
+	}
+
+	let mut raw_successors: Vec<Vec<G::Vertex>> = Vec::with_capacity(vertices.len());
+	while raw_successors.len() < vertices.len() {
+		let new_successors: Vec<Vec<G::Vertex>> = vertices[raw_successors.len()..]
+			.par_iter()
+			.map(|v| graph.successors(v.clone()).into_iter().collect())
+			.collect();
+
+		let unknown: Vec<G::Vertex> = new_successors
+			.par_iter()
+			.flat_map_iter(|successors| {
+				successors
+					.iter()
+					.filter(|w| !index.contains_key(*w))
+					.cloned()
+			})
+			.collect();
+
+		for w in unknown {
+			if let Entry::Vacant(entry) = index.entry(w.clone()) {
+				entry.insert(vertices.len());
+				vertices.push(w)
+			}
+		}
+
+		raw_successors.extend(new_successors)
+	}
+
+	let successors: Vec<Vec<usize>> = raw_successors
+		.into_par_iter()
+		.map(|successors| successors.into_iter().map(|w| index[&w]).collect())
+		.collect();
+
+	let mut predecessors = vec![Vec::new(); vertices.len()];
+	for (v, successors) in successors.iter().enumerate() {
+		for &w in successors {
+			predecessors[w].push(v)
+		}
+	}
+
+	let n = vertices.len();
+	let context = Context {
+		successors,
+		predecessors,
+		labels: (0..n).map(|_| AtomicUsize::new(0)).collect(),
+		next_label: AtomicUsize::new(1),
+		in_degree: (0..n).map(|_| AtomicUsize::new(0)).collect(),
+		out_degree: (0..n).map(|_| AtomicUsize::new(0)).collect(),
+		components: Mutex::new(Vec::new()),
+	};
+
+	rayon::scope(|scope| context.process(scope, (0..n).collect(), 0));
+
+	let list = context.components.into_inner().unwrap();
+	let mut component_of = vec![0; n];
+	for (i, component) in list.iter().enumerate() {
+		for &v in component {
+			component_of[v] = i
+		}
+	}
+
+	let component_successors: Vec<HashSet<usize, _>> = list
+		.par_iter()
+		.map(|component| {
+			component
+				.iter()
+				.flat_map(|&v| &context.successors[v])
+				.map(|&w| component_of[w])
+				.collect()
+		})
+		.collect();
+
+	let vertex_to_component = index
+		.into_iter()
+		.map(|(v, i)| (v, component_of[i]))
+		.collect();
+
+	let list = list
+		.into_iter()
+		.map(|component| component.into_iter().map(|v| vertices[v].clone()).collect())
+		.collect();
+
+	let components = Components::new(list, vertex_to_component, component_successors);
+
+	// Follow the same convention as Tarjan's algorithm, where components are
+	// listed in reverse topological order.
+	let mut order = components.topological_order();
+	order.reverse();
+	components.reorder(&order)
+}
@@ -0,0 +1,105 @@
+use std::collections::HashMap;
+
+use super::{Components, DefaultHashBuilder, Scc};
+
+// Pearce's space-efficient variant of Tarjan's SCC algorithm.
+//
+// Instead of storing an index, a lowlink, a stack flag and a component index
+// for each vertex, it only stores a single integer `rindex`:
+// - 0 while the vertex is not visited;
+// - its (updated) lowlink while it is being visited;
+// - `usize::MAX - c` where `c` is its component index once its component is
+//   found, which is always greater than any lowlink.
+//
+// See "A space-efficient algorithm for finding strongly connected components"
+// by David J. Pearce (2016).
+pub fn scc<G: ?Sized + Scc>(graph: &G) -> Components<G::Vertex> {
+	let mut rindex: HashMap<G::Vertex, usize, DefaultHashBuilder> =
+		HashMap::with_capacity_and_hasher(
+			graph.vertex_count_hint().unwrap_or(0),
+			DefaultHashBuilder::default(),
+		);
+	let mut stack = Vec::new();
+	let mut components: Vec<Vec<G::Vertex>> = Vec::new();
+	let mut index = 1;
+
+	for root in graph.vertices() {
+		if rindex.contains_key(&root) {
+			continue;
+		}
+
+		rindex.insert(root.clone(), index);
+		index += 1;
+
+		// DFS path, with the remaining successors of each vertex and whether
+		// or not it is the root of its component.
+		let mut path = vec![(root.clone(), graph.successors(root).into_iter(), true)];
+
+		while let Some((v, successors, is_root)) = path.last_mut() {
+			let v = v.clone();
+
+			match successors.next() {
+				Some(w) => match rindex.get(&w) {
+					None => {
+						rindex.insert(w.clone(), index);
+						index += 1;
+						path.push((w.clone(), graph.successors(w).into_iter(), true))
+					}
+					Some(&w_rindex) => {
+						if w_rindex < rindex[&v] {
+							rindex.insert(v.clone(), w_rindex);
+							*is_root = false
+						}
+					}
+				},
+				None => {
+					let is_root = *is_root;
+					path.pop();
+
+					let v_rindex = rindex[&v];
+					if is_root {
+						let c = usize::MAX - components.len();
+						let mut component = vec![v.clone()];
+						index -= 1;
+
+						while let Some(w) = stack.last() {
+							if v_rindex > rindex[w] {
+								break;
+							}
+
+							let w = stack.pop().unwrap();
+							rindex.insert(w.clone(), c);
+							component.push(w);
+							index -= 1
+						}
+
+						rindex.insert(v.clone(), c);
+						components.push(component)
+					} else {
+						stack.push(v.clone())
+					}
+
+					// Return to the caller, updating its lowlink
+					if let Some((u, _, u_is_root)) = path.last_mut() {
+						let v_rindex = rindex[&v];
+						if v_rindex < rindex[u] {
+							rindex.insert(u.clone(), v_rindex);
+							*u_is_root = false
+						}
+					}
+				}
+			}
+		}
+	}
+
+	let vertex_to_component: HashMap<_, _, _> = rindex
+		.into_iter()
+		.map(|(v, c)| (v, usize::MAX - c))
+		.collect();
+
+	let successors = Components::compute_successors(graph, &components, &vertex_to_component);
+	let components = Components::new(components, vertex_to_component, successors);
+
+	debug_assert!(components.is_condensation_acyclic());
+	components
+}
@@ -0,0 +1,133 @@
+//! [`Scc`] implementations for [`petgraph`] graph types.
+use std::hash::BuildHasher;
+
+use ::petgraph::{
+	csr::Csr,
+	graph::{DiGraph, Graph, IndexType, NodeIndex},
+	graphmap::{GraphMap, NodeTrait},
+	stable_graph::StableGraph,
+	Directed,
+};
+
+use crate::{Components, Scc};
+
+impl<N, E, Ix: IndexType> Scc for Graph<N, E, Directed, Ix> {
+	type Vertex = NodeIndex<Ix>;
+
+	fn vertices(&self) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+		self.node_indices()
+	}
+
+	fn successors(&self, v: Self::Vertex) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+		self.neighbors(v)
+	}
+
+	fn vertex_count_hint(&self) -> Option<usize> {
+		Some(self.node_count())
+	}
+
+	fn edge_count_hint(&self) -> Option<usize> {
+		Some(self.edge_count())
+	}
+}
+
+/// Indices of removed vertices are never yielded, so the components remain
+/// valid as long as the graph is not modified.
+impl<N, E, Ix: IndexType> Scc for StableGraph<N, E, Directed, Ix> {
+	type Vertex = NodeIndex<Ix>;
+
+	fn vertices(&self) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+		self.node_indices()
+	}
+
+	fn successors(&self, v: Self::Vertex) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+		self.neighbors(v)
+	}
+
+	fn vertex_count_hint(&self) -> Option<usize> {
+		Some(self.node_count())
+	}
+
+	fn edge_count_hint(&self) -> Option<usize> {
+		Some(self.edge_count())
+	}
+}
+
+impl<N: NodeTrait, E, S: BuildHasher> Scc for GraphMap<N, E, Directed, S> {
+	type Vertex = N;
+
+	fn vertices(&self) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+		self.nodes()
+	}
+
+	fn successors(&self, v: Self::Vertex) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+		self.neighbors(v)
+	}
+
+	fn vertex_count_hint(&self) -> Option<usize> {
+		Some(self.node_count())
+	}
+
+	fn edge_count_hint(&self) -> Option<usize> {
+		Some(self.edge_count())
+	}
+}
+
+/// Compressed sparse row graphs store the successors of each vertex in a
+/// contiguous slice, making this the fastest way to compute the strongly
+/// connected components of a large static petgraph graph.
+impl<N, E, Ix: IndexType> Scc for Csr<N, E, Directed, Ix> {
+	type Vertex = Ix;
+
+	fn vertices(&self) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+		(0..self.node_count()).map(Ix::new)
+	}
+
+	fn successors(&self, v: Self::Vertex) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+		self.neighbors_slice(v).iter().copied()
+	}
+
+	fn vertex_count_hint(&self) -> Option<usize> {
+		Some(self.node_count())
+	}
+
+	fn edge_count_hint(&self) -> Option<usize> {
+		Some(self.edge_count())
+	}
+}
+
+impl<V, S> Components<V, S> {
+	/// Converts the components into a petgraph condensation graph.
+	///
+	/// The weight of node `i` is the list of vertices of component `i`, and
+	/// there is an edge from `i` to `j` if `j` is a successor of `i`. Self-loops
+	/// of cyclic components are omitted, so the resulting graph is acyclic and
+	/// can be passed to petgraph's `toposort` or `dominators` algorithms.
+	///
+	/// ```
+	/// use scc_trait::Scc;
+	///
+	/// let graph = vec![vec![1], vec![0, 2], vec![]];
+	/// let condensation = graph
+	///   .strongly_connected_components()
+	///   .into_petgraph_condensation();
+	/// assert_eq!(condensation.node_count(), 2);
+	/// assert_eq!(condensation.edge_count(), 1);
+	/// ```
+	pub fn into_petgraph_condensation(self) -> DiGraph<Vec<V>, ()> {
+		let mut result = DiGraph::with_capacity(self.list.len(), 0);
+		for component in self.list {
+			result.add_node(component);
+		}
+
+		for (i, successors) in self.successors.into_iter().enumerate() {
+			let mut successors: Vec<_> = successors.into_iter().filter(|&j| j != i).collect();
+			successors.sort_unstable();
+			for j in successors {
+				result.add_edge(NodeIndex::new(i), NodeIndex::new(j), ());
+			}
+		}
+
+		result
+	}
+}
@@ -0,0 +1,162 @@
+use std::collections::HashMap;
+
+use super::{
+	tarjan::{self, Data, Iter, Step},
+	Components, DefaultHashBuilder, Scc,
+};
+
+/// Intermediate state of a resumable strongly connected components
+/// computation.
+///
+/// Created by [`Scc::strongly_connected_components_resumable`], and advanced
+/// by [`Scc::resume`]. With the `serde` feature enabled, the state can be
+/// serialized to resume the computation later, for instance in another
+/// process.
+///
+/// The state does not hold on to the graph iterators, but remembers how far
+/// it went through them. Hence the graph must enumerate its vertices and
+/// successors in the same order every time the computation is resumed.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+	feature = "serde",
+	serde(bound(
+		serialize = "V: serde::Serialize",
+		deserialize = "V: Eq + std::hash::Hash + serde::Deserialize<'de>"
+	))
+)]
+pub struct SccProgress<V> {
+	/// Number of vertices of the graph already used as DFS roots.
+	roots: usize,
+
+	/// Visited vertices.
+	map: HashMap<V, Data, DefaultHashBuilder>,
+
+	/// Tarjan's vertex stack.
+	stack: Vec<V>,
+
+	/// DFS path, with the number of successors already visited for each
+	/// vertex.
+	path: Vec<(V, usize)>,
+
+	/// Components found so far.
+	components: Vec<Vec<V>>,
+}
+
+impl<V> SccProgress<V> {
+	/// Creates a new computation state, where nothing has been computed yet.
+	pub fn new() -> Self {
+		Self {
+			roots: 0,
+			map: HashMap::default(),
+			stack: Vec::new(),
+			path: Vec::new(),
+			components: Vec::new(),
+		}
+	}
+
+	/// Returns the number of vertices visited so far.
+	pub fn visited_vertices(&self) -> usize {
+		self.map.len()
+	}
+
+	/// Returns the number of components found so far.
+	pub fn components_found(&self) -> usize {
+		self.components.len()
+	}
+}
+
+impl<V> Default for SccProgress<V> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+/// Iterator remembering how many items it returned.
+struct Counted<I> {
+	/// Underlying iterator.
+	inner: I,
+
+	/// Number of items returned so far.
+	count: usize,
+}
+
+impl<I: Iterator> Counted<I> {
+	fn new(inner: I) -> Self {
+		Self { inner, count: 0 }
+	}
+
+	/// Skips the first `count` items of `inner`.
+	fn skip(mut inner: I, count: usize) -> Self {
+		for _ in 0..count {
+			inner.next();
+		}
+
+		Self { inner, count }
+	}
+}
+
+impl<I: Iterator> Iterator for Counted<I> {
+	type Item = I::Item;
+
+	fn next(&mut self) -> Option<I::Item> {
+		let item = self.inner.next()?;
+		self.count += 1;
+		Some(item)
+	}
+}
+
+/// Runs at most `max_steps` steps of Tarjan's algorithm from the given state,
+/// where each step visits one vertex or one edge.
+///
+/// Returns the components if the computation is complete.
+pub fn resume<G: ?Sized + Scc>(
+	graph: &G,
+	progress: &mut SccProgress<G::Vertex>,
+	max_steps: usize,
+) -> Option<Components<G::Vertex>> {
+	let successors = move |v| Counted::new(graph.successors(v).into_iter());
+
+	// Restore the successor iterators of the DFS path.
+	let path = std::mem::take(&mut progress.path)
+		.into_iter()
+		.map(|(v, visited)| {
+			let successors = Counted::skip(graph.successors(v.clone()).into_iter(), visited);
+			(v, successors)
+		})
+		.collect();
+
+	let mut iter = Iter::from_state(
+		Counted::skip(graph.vertices().into_iter(), progress.roots),
+		successors,
+		std::mem::take(&mut progress.map),
+		std::mem::take(&mut progress.stack),
+		path,
+	);
+
+	for _ in 0..max_steps {
+		match iter.step() {
+			Step::Continue => (),
+			Step::Root(v) => {
+				let mut component = Vec::new();
+				iter.pop_component(v, |w| component.push(w));
+				progress.components.push(component)
+			}
+			Step::Done => {
+				let components = std::mem::take(&mut progress.components);
+				*progress = SccProgress::new();
+				return Some(tarjan::collect(
+					graph,
+					components,
+					DefaultHashBuilder::default(),
+				));
+			}
+		}
+	}
+
+	let (roots, map, stack, path) = iter.into_state();
+	progress.roots = roots.count;
+	progress.map = map;
+	progress.stack = stack;
+	progress.path = path.into_iter().map(|(v, s)| (v, s.count)).collect();
+	None
+}
@@ -0,0 +1,58 @@
+use super::{Scc, SccBidirectional};
+
+/// Graph adapter flipping the direction of every edge of the underlying
+/// graph.
+///
+/// Created by [`SccBidirectional::reversed`]. The successors of a vertex are
+/// its predecessors in the underlying graph, and conversely. The strongly
+/// connected components are the same as those of the underlying graph, but
+/// reachability is reversed.
+pub struct Reversed<'a, G: ?Sized> {
+	graph: &'a G,
+}
+
+impl<'a, G: ?Sized> Reversed<'a, G> {
+	/// Creates a new adapter reversing the edges of `graph`.
+	pub fn new(graph: &'a G) -> Self {
+		Self { graph }
+	}
+
+	/// Returns the underlying graph.
+	pub fn graph(&self) -> &'a G {
+		self.graph
+	}
+}
+
+impl<G: ?Sized> Clone for Reversed<'_, G> {
+	fn clone(&self) -> Self {
+		*self
+	}
+}
+
+impl<G: ?Sized> Copy for Reversed<'_, G> {}
+
+impl<G: ?Sized + SccBidirectional> Scc for Reversed<'_, G> {
+	type Vertex = G::Vertex;
+
+	fn vertices(&self) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+		self.graph.vertices()
+	}
+
+	fn successors(&self, v: Self::Vertex) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+		self.graph.predecessors(v)
+	}
+
+	fn vertex_count_hint(&self) -> Option<usize> {
+		self.graph.vertex_count_hint()
+	}
+
+	fn edge_count_hint(&self) -> Option<usize> {
+		self.graph.edge_count_hint()
+	}
+}
+
+impl<G: ?Sized + SccBidirectional> SccBidirectional for Reversed<'_, G> {
+	fn predecessors(&self, v: Self::Vertex) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+		self.graph.successors(v)
+	}
+}
@@ -0,0 +1,59 @@
+//! [`Scc`] implementations for [`slotmap`] maps.
+use ::slotmap::{Key, SecondaryMap, SlotMap};
+
+use crate::Scc;
+
+/// Adjacency slot map, where `C` is any successor container (`Vec`,
+/// `HashSet`, etc.).
+///
+/// Successors that are no longer in the map, such as keys of removed
+/// vertices, are still reported as successors, but have no successors
+/// themselves.
+///
+/// ```
+/// use slotmap::{DefaultKey, SlotMap};
+/// use scc_trait::Scc;
+///
+/// let mut graph: SlotMap<DefaultKey, Vec<DefaultKey>> = SlotMap::new();
+/// let a = graph.insert(Vec::new());
+/// let b = graph.insert(vec![a]);
+/// graph[a].push(b);
+/// graph.insert(vec![a]);
+///
+/// let components = graph.strongly_connected_components();
+/// assert_eq!(components.len(), 2);
+/// assert!(components.same_component(&a, &b));
+/// ```
+impl<K: Key, C> Scc for SlotMap<K, C>
+where
+	for<'a> &'a C: IntoIterator<Item = &'a K>,
+{
+	type Vertex = K;
+
+	fn vertices(&self) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+		self.keys()
+	}
+
+	fn successors(&self, v: Self::Vertex) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+		self.get(v).into_iter().flatten().copied()
+	}
+}
+
+/// Adjacency map attached to the keys of a primary slot map.
+///
+/// Only the keys present in the secondary map are enumerated as vertices.
+/// Successors that are not in the map have no successors themselves.
+impl<K: Key, C> Scc for SecondaryMap<K, C>
+where
+	for<'a> &'a C: IntoIterator<Item = &'a K>,
+{
+	type Vertex = K;
+
+	fn vertices(&self) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+		self.keys()
+	}
+
+	fn successors(&self, v: Self::Vertex) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+		self.get(v).into_iter().flatten().copied()
+	}
+}
@@ -1,122 +1,476 @@
-use std::collections::{HashMap, HashSet};
+use std::{
+	cell::RefCell,
+	collections::{HashMap, HashSet},
+	hash::{BuildHasher, Hash},
+};
 
-use super::{Components, Scc};
+use super::{
+	dense, CompactComponents, Components, DefaultHashBuilder, DenseScc, IndexType, Scc, SccVisitor,
+	TryScc,
+};
 
 // Solve dependencies using Tarjan's SCC algorithm.
-struct Data {
+#[derive(Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct Data {
 	index: u32,
 	lowlink: u32,
 	on_stack: bool,
-	component: usize,
 }
 
 pub fn scc<G: ?Sized + Scc>(graph: &G) -> Components<G::Vertex> {
-	let mut map: HashMap<G::Vertex, Data> = HashMap::new();
-	let mut stack = Vec::new();
-	let mut components = Vec::new();
+	scc_with_hasher(graph, DefaultHashBuilder::default())
+}
 
-	for v in graph.vertices() {
-		if !map.contains_key(&v) {
-			strong_connect(graph, v, &mut stack, &mut map, &mut components);
-		}
+/// Computes the strongly connected components of `graph`, using `hasher` for
+/// every vertex map.
+pub fn scc_with_hasher<G: ?Sized + Scc, H: BuildHasher + Clone>(
+	graph: &G,
+	hasher: H,
+) -> Components<G::Vertex, H> {
+	let mut iter = Iter::with_hasher(
+		graph.vertices().into_iter(),
+		move |v| graph.successors(v).into_iter(),
+		hasher.clone(),
+	);
+	iter.reserve(graph.vertex_count_hint().unwrap_or(0));
+
+	collect(graph, iter.collect(), hasher)
+}
+
+/// Computes the strongly connected components of `graph`, using `Ix` for
+/// component indices.
+pub fn scc_compact<G: ?Sized + Scc, Ix: IndexType>(graph: &G) -> CompactComponents<G::Vertex, Ix> {
+	CompactComponents::from_list(graph, iter(graph).collect())
+}
+
+/// Computes the strongly connected components reachable from `roots`.
+pub fn scc_from<G: ?Sized + Scc>(
+	graph: &G,
+	roots: impl IntoIterator<Item = G::Vertex>,
+) -> Components<G::Vertex> {
+	let components =
+		Iter::new(roots.into_iter(), move |v| graph.successors(v).into_iter()).collect();
+
+	collect(graph, components, DefaultHashBuilder::default())
+}
+
+/// Builds the components found by Tarjan's algorithm.
+pub(crate) fn collect<G: ?Sized + Scc, H: BuildHasher>(
+	graph: &G,
+	components: Vec<Vec<G::Vertex>>,
+	hasher: H,
+) -> Components<G::Vertex, H> {
+	let len = components.iter().map(Vec::len).sum();
+	let mut vertex_to_component = HashMap::with_capacity_and_hasher(len, hasher);
+	vertex_to_component.extend(
+		components
+			.iter()
+			.enumerate()
+			.flat_map(|(i, component)| component.iter().map(move |v| (v.clone(), i))),
+	);
+
+	let successors = Components::compute_successors(graph, &components, &vertex_to_component);
+	let components = Components::new(components, vertex_to_component, successors);
+
+	debug_assert!(components.is_condensation_acyclic());
+	components
+}
+
+/// Computes the strongly connected components of a fallible graph.
+///
+/// The search stops at the end of the current component as soon as an error
+/// is returned by the graph, and the error is returned. The successors of each
+/// vertex are recorded during the search to compute the condensation graph,
+/// so that they are only fetched once.
+pub fn try_scc<G: ?Sized + TryScc>(graph: &G) -> Result<Components<G::Vertex>, G::Error> {
+	let error = RefCell::new(None);
+	let recorded: RefCell<HashMap<G::Vertex, Vec<G::Vertex>, DefaultHashBuilder>> =
+		RefCell::default();
+
+	let components: Vec<_> = {
+		let vertices = graph
+			.vertices()?
+			.into_iter()
+			.take_while(|_| error.borrow().is_none());
+
+		let successors = |v: G::Vertex| {
+			match graph.successors(v.clone()) {
+				Ok(successors) => {
+					let successors: Vec<_> = successors.into_iter().collect();
+					recorded.borrow_mut().insert(v, successors.clone());
+					Some(successors.into_iter())
+				}
+				Err(e) => {
+					*error.borrow_mut() = Some(e);
+					None
+				}
+			}
+			.into_iter()
+			.flatten()
+		};
+
+		Iter::new(vertices, successors)
+			.take_while(|_| error.borrow().is_none())
+			.collect()
+	};
+
+	if let Some(e) = error.into_inner() {
+		return Err(e);
 	}
 
-	let vertex_to_component: HashMap<_, _> = map
-		.into_iter()
-		.map(|(v, data)| (v, data.component))
+	let vertex_to_component: HashMap<_, _, _> = components
+		.iter()
+		.enumerate()
+		.flat_map(|(i, component)| component.iter().map(move |v| (v.clone(), i)))
 		.collect();
 
-	let successors: Vec<HashSet<_>> = components
+	let recorded = recorded.into_inner();
+	let successors = components
 		.iter()
 		.map(|component| {
 			component
 				.iter()
-				.flat_map(|v| {
-					graph
-						.successors(*v)
-						.into_iter()
-						.map(|sc| *vertex_to_component.get(&sc).unwrap())
-				})
-				.collect()
+				.flat_map(|v| &recorded[v])
+				.map(|w| vertex_to_component[w])
+				.collect::<HashSet<_, _>>()
 		})
 		.collect();
 
-	Components {
-		vertex_to_component,
-		list: components,
-		successors,
+	Ok(Components::new(components, vertex_to_component, successors))
+}
+
+/// Returns an iterator over the strongly connected components of `graph`,
+/// in the order they are found by Tarjan's algorithm.
+pub fn iter<G: ?Sized + Scc>(graph: &G) -> impl '_ + Iterator<Item = Vec<G::Vertex>> {
+	let mut iter = Iter::new(graph.vertices().into_iter(), move |v| {
+		graph.successors(v).into_iter()
+	});
+	iter.reserve(graph.vertex_count_hint().unwrap_or(0));
+	iter
+}
+
+/// Returns the strongly connected component of `v`.
+///
+/// The search starts from `v` and only visits the vertices reachable from it.
+/// Since `v` is the root of the search, its component is the last one found.
+pub fn component_of<G: ?Sized + Scc>(graph: &G, v: G::Vertex) -> Vec<G::Vertex> {
+	Iter::new(std::iter::once(v), move |v| graph.successors(v).into_iter())
+		.last()
+		.unwrap()
+}
+
+/// Feeds the strongly connected components of `graph` to `visitor`, in the
+/// order they are found by Tarjan's algorithm.
+pub fn visit<G: ?Sized + Scc, T: SccVisitor<G::Vertex>>(graph: &G, visitor: &mut T) {
+	let mut iter = Iter::new(graph.vertices().into_iter(), move |v| {
+		graph.successors(v).into_iter()
+	});
+	while let Some(root) = iter.next_root() {
+		visitor.begin_component();
+		iter.pop_component(root, |v| visitor.visit_vertex(v));
+		visitor.end_component()
 	}
 }
 
-fn strong_connect<G: ?Sized + Scc>(
-	graph: &G,
-	v: G::Vertex,
-	stack: &mut Vec<G::Vertex>,
-	map: &mut HashMap<G::Vertex, Data>,
-	components: &mut Vec<Vec<G::Vertex>>,
-) -> u32 {
-	let index = map.len() as u32;
-	stack.push(v);
-	map.insert(
-		v,
-		Data {
-			index,
-			lowlink: index,
-			on_stack: true,
-			component: 0,
-		},
-	);
+/// Reusable memory for Tarjan's algorithm.
+///
+/// Computing strongly connected components requires allocating a map of
+/// visited vertices and a stack, both as large as the graph. When computing
+/// the components of many similar graphs, a workspace keeps these
+/// allocations around between computations, so that they are only made once.
+///
+/// ```
+/// use scc_trait::SccWorkspace;
+///
+/// let mut workspace = SccWorkspace::new();
+/// for n in 1..10 {
+///   let graph: Vec<Vec<usize>> = (0..n).map(|i| vec![(i + 1) % n]).collect();
+///   assert_eq!(workspace.compute_dense(&graph).len(), 1);
+/// }
+/// ```
+pub struct SccWorkspace<V> {
+	/// Visited vertices.
+	map: HashMap<V, Data, DefaultHashBuilder>,
 
-	// Consider successors of v
-	for w in graph.successors(v) {
-		let new_v_lowlink = match map.get(&w) {
-			None => {
-				// Successor w has not yet been visited; recurse on it
-				let w_lowlink = strong_connect(graph, w, stack, map, components);
-				Some(std::cmp::min(map[&v].lowlink, w_lowlink))
-			}
-			Some(w_data) => {
-				if w_data.on_stack {
-					// Successor w is in stack S and hence in the current SCC
-					// If w is not on stack, then (v, w) is an edge pointing to an SCC already found and must be ignored
-					// Note: The next line may look odd - but is correct.
-					// It says w.index not w.lowlink; that is deliberate and from the original paper
-					Some(std::cmp::min(map[&v].lowlink, w_data.index))
-				} else {
-					None
-				}
-			}
-		};
+	/// Tarjan's stack.
+	stack: Vec<V>,
+
+	/// Arrays of the dense algorithm.
+	dense: dense::Buffers,
+}
 
-		if let Some(new_v_lowlink) = new_v_lowlink {
-			map.get_mut(&v).unwrap().lowlink = new_v_lowlink;
+impl<V> SccWorkspace<V> {
+	/// Creates a new empty workspace.
+	pub fn new() -> Self {
+		Self {
+			map: HashMap::default(),
+			stack: Vec::new(),
+			dense: dense::Buffers::default(),
 		}
 	}
+}
 
-	let lowlink = map[&v].lowlink;
+impl<V> Default for SccWorkspace<V> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
 
-	// If v is a root node, pop the stack and generate an SCC
-	if lowlink == map[&v].index {
-		// Start a new strongly connected component
-		let mut component = Vec::new();
+impl<V: Clone + Eq + Hash> SccWorkspace<V> {
+	/// Computes the strongly connected components of `graph`, using this
+	/// workspace for the intermediate data.
+	///
+	/// The result is the same as
+	/// [`Scc::strongly_connected_components`]. Graphs whose vertices are the
+	/// dense range `0..n` should use [`compute_dense`](Self::compute_dense)
+	/// instead.
+	pub fn compute<G: ?Sized + Scc<Vertex = V>>(&mut self, graph: &G) -> Components<V> {
+		let mut iter = Iter::from_parts(
+			graph.vertices().into_iter(),
+			move |v| graph.successors(v).into_iter(),
+			std::mem::take(&mut self.map),
+			std::mem::take(&mut self.stack),
+		);
+		iter.reserve(graph.vertex_count_hint().unwrap_or(0));
+
+		let components = iter.by_ref().collect();
+		(self.map, self.stack) = iter.into_parts();
+		self.map.clear();
+		self.stack.clear();
+
+		collect(graph, components, DefaultHashBuilder::default())
+	}
+}
+
+impl SccWorkspace<usize> {
+	/// Computes the strongly connected components of `graph` using
+	/// vertex-indexed arrays, using this workspace for the intermediate data.
+	///
+	/// The result is the same as
+	/// [`DenseScc::strongly_connected_components_dense`].
+	///
+	/// # Panics
+	///
+	/// Panics if a successor is not lower than
+	/// [`vertex_count`](DenseScc::vertex_count).
+	pub fn compute_dense<G: ?Sized + DenseScc>(&mut self, graph: &G) -> Components<usize> {
+		dense::scc_with_buffers(graph, &mut self.dense)
+	}
+}
 
+/// Remaining search roots, visited map, stack and DFS path of a search.
+pub(crate) type State<V, I, S, H> = (I, HashMap<V, Data, H>, Vec<V>, Vec<(V, S)>);
+
+/// Outcome of a single step of the search.
+pub(crate) enum Step<V> {
+	/// The search goes on.
+	Continue,
+
+	/// The given vertex is the root of a strongly connected component, which
+	/// is on top of the stack.
+	Root(V),
+
+	/// Every vertex has been visited.
+	Done,
+}
+
+/// Lazy Tarjan's algorithm.
+///
+/// The search uses an explicit stack instead of recursion, so that deep graphs
+/// do not overflow the call stack.
+pub(crate) struct Iter<V, I, F, S, H = DefaultHashBuilder> {
+	/// Remaining search roots.
+	vertices: I,
+
+	/// Successors function.
+	successors: F,
+
+	/// DFS path, with the remaining successors of each vertex.
+	path: Vec<(V, S)>,
+
+	/// Tarjan's stack.
+	stack: Vec<V>,
+
+	/// Visited vertices.
+	map: HashMap<V, Data, H>,
+}
+
+impl<V: Clone + Eq + Hash, I, F, S> Iter<V, I, F, S>
+where
+	I: Iterator<Item = V>,
+	F: FnMut(V) -> S,
+	S: Iterator<Item = V>,
+{
+	fn new(vertices: I, successors: F) -> Self {
+		Self::with_hasher(vertices, successors, DefaultHashBuilder::default())
+	}
+}
+
+impl<V: Clone + Eq + Hash, I, F, S, H: BuildHasher> Iter<V, I, F, S, H>
+where
+	I: Iterator<Item = V>,
+	F: FnMut(V) -> S,
+	S: Iterator<Item = V>,
+{
+	fn with_hasher(vertices: I, successors: F, hasher: H) -> Self {
+		Self::from_parts(
+			vertices,
+			successors,
+			HashMap::with_hasher(hasher),
+			Vec::new(),
+		)
+	}
+
+	/// Creates a search reusing the given (empty) visited map and stack.
+	fn from_parts(vertices: I, successors: F, map: HashMap<V, Data, H>, stack: Vec<V>) -> Self {
+		Self::from_state(vertices, successors, map, stack, Vec::new())
+	}
+
+	/// Creates a search resuming from the given visited map, stack and DFS
+	/// path.
+	pub(crate) fn from_state(
+		vertices: I,
+		successors: F,
+		map: HashMap<V, Data, H>,
+		stack: Vec<V>,
+		path: Vec<(V, S)>,
+	) -> Self {
+		Self {
+			vertices,
+			successors,
+			path,
+			stack,
+			map,
+		}
+	}
+
+	/// Returns the remaining search roots, visited map, stack and DFS path of
+	/// the search, so that it can be resumed later.
+	pub(crate) fn into_state(self) -> State<V, I, S, H> {
+		(self.vertices, self.map, self.stack, self.path)
+	}
+
+	/// Returns the visited map and stack of the search, so that their
+	/// allocations can be reused.
+	fn into_parts(self) -> (HashMap<V, Data, H>, Vec<V>) {
+		(self.map, self.stack)
+	}
+
+	/// Reserves space for at least `additional` more visited vertices.
+	fn reserve(&mut self, additional: usize) {
+		self.map.reserve(additional)
+	}
+
+	fn visit(&mut self, v: V) {
+		let index = self.map.len() as u32;
+		self.stack.push(v.clone());
+		self.map.insert(
+			v.clone(),
+			Data {
+				index,
+				lowlink: index,
+				on_stack: true,
+			},
+		);
+
+		self.path.push((v.clone(), (self.successors)(v)))
+	}
+
+	/// Runs the search until the root of the next strongly connected
+	/// component is found.
+	fn next_root(&mut self) -> Option<V> {
 		loop {
-			let w = stack.pop().unwrap();
-			let w_data = map.get_mut(&w).unwrap();
-			w_data.on_stack = false;
-			w_data.component = components.len();
+			match self.step() {
+				Step::Continue => (),
+				Step::Root(v) => return Some(v),
+				Step::Done => return None,
+			}
+		}
+	}
+
+	/// Runs a single step of the search, visiting one vertex or one edge.
+	pub(crate) fn step(&mut self) -> Step<V> {
+		let Some((v, successors)) = self.path.last_mut() else {
+			// Start a new search from the next unvisited vertex.
+			return match self.vertices.find(|v| !self.map.contains_key(v)) {
+				Some(root) => {
+					self.visit(root);
+					Step::Continue
+				}
+				None => Step::Done,
+			};
+		};
 
-			// Add w to current strongly connected component
-			component.push(w);
+		let v = v.clone();
 
-			if w == v {
-				break;
+		// Consider successors of v
+		match successors.next() {
+			Some(w) => match self.map.get(&w) {
+				None => {
+					// Successor w has not yet been visited; recurse on it
+					self.visit(w)
+				}
+				Some(w_data) => {
+					if w_data.on_stack {
+						// Successor w is in stack S and hence in the current SCC
+						// If w is not on stack, then (v, w) is an edge pointing to an SCC already found and must be ignored
+						// Note: The next line may look odd - but is correct.
+						// It says w.index not w.lowlink; that is deliberate and from the original paper
+						let w_index = w_data.index;
+						let v_data = self.map.get_mut(&v).unwrap();
+						v_data.lowlink = std::cmp::min(v_data.lowlink, w_index)
+					}
+				}
+			},
+			None => {
+				self.path.pop();
+
+				let lowlink = self.map[&v].lowlink;
+
+				// Return to the caller, updating its lowlink
+				if let Some((u, _)) = self.path.last() {
+					let u_data = self.map.get_mut(u).unwrap();
+					u_data.lowlink = std::cmp::min(u_data.lowlink, lowlink)
+				}
+
+				// If v is a root node, its SCC is on top of the stack
+				if lowlink == self.map[&v].index {
+					return Step::Root(v);
+				}
 			}
 		}
 
-		// Output the current strongly connected component
-		components.push(component)
+		Step::Continue
+	}
+
+	/// Pops the strongly connected component of `root` from the stack.
+	pub(crate) fn pop_component(&mut self, root: V, mut f: impl FnMut(V)) {
+		loop {
+			let w = self.stack.pop().unwrap();
+			self.map.get_mut(&w).unwrap().on_stack = false;
+			let is_root = w == root;
+			f(w);
+
+			if is_root {
+				break;
+			}
+		}
 	}
+}
 
-	lowlink
+impl<V: Clone + Eq + Hash, I, F, S, H: BuildHasher> Iterator for Iter<V, I, F, S, H>
+where
+	I: Iterator<Item = V>,
+	F: FnMut(V) -> S,
+	S: Iterator<Item = V>,
+{
+	type Item = Vec<V>;
+
+	fn next(&mut self) -> Option<Vec<V>> {
+		let root = self.next_root()?;
+		let mut component = Vec::new();
+		self.pop_component(root, |v| component.push(v));
+		Some(component)
+	}
 }
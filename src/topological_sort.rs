@@ -0,0 +1,59 @@
+use std::collections::HashMap;
+
+use super::Scc;
+
+/// Sorts the vertices of the graph in topological order using a depth-first
+/// search, or returns a cycle if the graph is not acyclic.
+pub fn sort<G: ?Sized + Scc>(graph: &G) -> Result<Vec<G::Vertex>, Vec<G::Vertex>> {
+	let mut post_order = Vec::new();
+	search(graph, |v| post_order.push(v))?;
+	post_order.reverse();
+	Ok(post_order)
+}
+
+/// Depth-first search of the whole graph, calling `post` on each vertex once
+/// all its successors are visited.
+///
+/// Stops as soon as a cycle is found, and returns it.
+pub fn search<G: ?Sized + Scc>(
+	graph: &G,
+	mut post: impl FnMut(G::Vertex),
+) -> Result<(), Vec<G::Vertex>> {
+	// Maps each visited vertex to `true` once all its successors are visited.
+	let mut visited: HashMap<G::Vertex, bool> = HashMap::new();
+
+	for root in graph.vertices() {
+		if visited.contains_key(&root) {
+			continue;
+		}
+
+		visited.insert(root.clone(), false);
+		let mut stack = vec![(root.clone(), graph.successors(root).into_iter())];
+
+		while let Some((v, successors)) = stack.last_mut() {
+			let v = v.clone();
+			match successors.next() {
+				Some(w) => match visited.get(&w) {
+					None => {
+						visited.insert(w.clone(), false);
+						stack.push((w.clone(), graph.successors(w).into_iter()))
+					}
+					Some(false) => {
+						// `w` is on the current path: the path from `w` to `v`
+						// followed by the edge `v → w` is a cycle.
+						let start = stack.iter().position(|(u, _)| *u == w).unwrap();
+						return Err(stack.drain(start..).map(|(u, _)| u).collect());
+					}
+					Some(true) => (),
+				},
+				None => {
+					visited.insert(v.clone(), true);
+					post(v);
+					stack.pop();
+				}
+			}
+		}
+	}
+
+	Ok(())
+}
@@ -0,0 +1,99 @@
+use super::Scc;
+
+/// 2-SAT problem builder and solver.
+///
+/// Each clause is a disjunction of two literals, where a literal `(x, value)`
+/// states that variable `x` equals `value`. Variables are indexed from `0`,
+/// and are added as they appear in clauses.
+///
+/// The problem is solved by computing the strongly connected components of
+/// its implication graph.
+///
+/// ```
+/// use scc_trait::TwoSat;
+///
+/// let mut problem = TwoSat::new(2);
+/// problem.add_clause((0, true), (1, true)); // x0 ∨ x1
+/// problem.add_clause((0, false), (1, false)); // ¬x0 ∨ ¬x1
+/// problem.add_clause((0, false), (0, false)); // ¬x0
+///
+/// assert_eq!(problem.solve(), Ok(vec![false, true]));
+///
+/// problem.add_clause((1, false), (1, false)); // ¬x1
+/// assert!(problem.solve().is_err());
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct TwoSat {
+	/// Implication graph, where literal `(x, true)` is vertex `2 * x` and
+	/// literal `(x, false)` is vertex `2 * x + 1`.
+	implications: Vec<Vec<usize>>,
+}
+
+impl TwoSat {
+	/// Creates a new problem with the given number of variables and no
+	/// clauses.
+	pub fn new(variables: usize) -> Self {
+		Self {
+			implications: vec![Vec::new(); 2 * variables],
+		}
+	}
+
+	/// Returns the number of variables.
+	pub fn variable_count(&self) -> usize {
+		self.implications.len() / 2
+	}
+
+	/// Adds a new variable, and returns its index.
+	pub fn add_variable(&mut self) -> usize {
+		let x = self.variable_count();
+		self.implications.resize(2 * x + 2, Vec::new());
+		x
+	}
+
+	fn literal(&mut self, (x, value): (usize, bool)) -> usize {
+		if x >= self.variable_count() {
+			self.implications.resize(2 * x + 2, Vec::new());
+		}
+
+		2 * x + !value as usize
+	}
+
+	/// Adds the clause `a ∨ b`.
+	///
+	/// Adding the clause `a ∨ a` forces literal `a` to hold.
+	pub fn add_clause(&mut self, a: (usize, bool), b: (usize, bool)) {
+		let a = self.literal(a);
+		let b = self.literal(b);
+		self.implications[a ^ 1].push(b);
+		self.implications[b ^ 1].push(a);
+	}
+
+	/// Adds the clause `a → b`, equivalent to `¬a ∨ b`.
+	pub fn add_implication(&mut self, (x, value): (usize, bool), b: (usize, bool)) {
+		self.add_clause((x, !value), b)
+	}
+
+	/// Solves the problem.
+	///
+	/// Returns a value for each variable satisfying all the clauses, or a
+	/// variable `x` such that `x` and `¬x` imply each other if the problem is
+	/// unsatisfiable.
+	pub fn solve(&self) -> Result<Vec<bool>, usize> {
+		let components = self.implications.strongly_connected_components();
+
+		(0..self.variable_count())
+			.map(|x| {
+				let positive = components.vertex_component_index(&(2 * x)).unwrap();
+				let negative = components.vertex_component_index(&(2 * x + 1)).unwrap();
+
+				// Components are in reverse topological order: the literal
+				// coming last in topological order holds.
+				match positive.cmp(&negative) {
+					std::cmp::Ordering::Less => Ok(true),
+					std::cmp::Ordering::Greater => Ok(false),
+					std::cmp::Ordering::Equal => Err(x),
+				}
+			})
+			.collect()
+	}
+}
@@ -0,0 +1,158 @@
+use std::collections::HashSet;
+
+use super::Scc;
+
+/// Union of several graphs sharing the same vertex type.
+///
+/// The vertices of the union are the vertices of all the constituent graphs,
+/// and the successors of a vertex are its successors in every graph. This
+/// allows computing the strongly connected components of the combined graph
+/// without merging the graphs.
+///
+/// Every graph must accept any vertex of the union as input of
+/// [`successors`](Scc::successors), even vertices it does not contain.
+pub struct Union<'a, G> {
+	graphs: &'a [G],
+}
+
+impl<'a, G> Union<'a, G> {
+	/// Creates the union of the given graphs.
+	pub fn new(graphs: &'a [G]) -> Self {
+		Self { graphs }
+	}
+}
+
+impl<G: Scc> Scc for Union<'_, G> {
+	type Vertex = G::Vertex;
+
+	fn vertices(&self) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+		let mut visited = HashSet::new();
+		self.graphs
+			.iter()
+			.flat_map(Scc::vertices)
+			.filter(move |v| visited.insert(v.clone()))
+	}
+
+	fn successors(&self, v: Self::Vertex) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+		self.graphs
+			.iter()
+			.flat_map(move |g| g.successors(v.clone()))
+	}
+}
+
+/// Union of two graphs sharing the same vertex type.
+///
+/// Similar to [`Union`], but the two graphs may have different types. The
+/// vertices of the overlay are the vertices of both graphs, and the
+/// successors of a vertex are its successors in both graphs. It is named
+/// `Overlay` rather than `Union<G1, G2>` since [`Union`] already designates
+/// the union of a slice of graphs of the same type.
+///
+/// Both graphs must accept any vertex of the overlay as input of
+/// [`successors`](Scc::successors), even vertices they do not contain.
+///
+/// ```
+/// use scc_trait::{Overlay, Scc};
+///
+/// let runtime = vec![vec![1], vec![], vec![]];
+/// let build = vec![vec![], vec![0], vec![]];
+/// let components = Overlay::new(&runtime, &build).strongly_connected_components();
+/// assert_eq!(components.len(), 2);
+/// ```
+pub struct Overlay<G1, G2> {
+	first: G1,
+	second: G2,
+}
+
+impl<G1, G2> Overlay<G1, G2> {
+	/// Creates the overlay of the given graphs.
+	pub fn new(first: G1, second: G2) -> Self {
+		Self { first, second }
+	}
+}
+
+impl<G1, G2> Scc for Overlay<G1, G2>
+where
+	G1: Scc,
+	G2: Scc<Vertex = G1::Vertex>,
+{
+	type Vertex = G1::Vertex;
+
+	fn vertices(&self) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+		let mut visited = HashSet::new();
+		self.first
+			.vertices()
+			.into_iter()
+			.chain(self.second.vertices())
+			.filter(move |v| visited.insert(v.clone()))
+	}
+
+	fn successors(&self, v: Self::Vertex) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+		self.first
+			.successors(v.clone())
+			.into_iter()
+			.chain(self.second.successors(v))
+	}
+}
+
+/// Vertex of a [`DisjointUnion`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Either<L, R> {
+	/// Vertex of the first graph.
+	Left(L),
+
+	/// Vertex of the second graph.
+	Right(R),
+}
+
+/// Disjoint union of two graphs.
+///
+/// The vertices of the first graph are wrapped in [`Either::Left`], and the
+/// vertices of the second graph in [`Either::Right`], so that the two graphs
+/// never share any vertex or edge, even if their vertex types are the same.
+///
+/// ```
+/// use scc_trait::{DisjointUnion, Either, Scc};
+///
+/// let a = vec![vec![1], vec![0]];
+/// let b = vec![vec![0]];
+/// let union = DisjointUnion::new(&a, &b);
+/// let components = union.strongly_connected_components();
+/// assert_eq!(components.len(), 2);
+/// assert!(!components.same_component(&Either::Left(0), &Either::Right(0)));
+/// ```
+pub struct DisjointUnion<G1, G2> {
+	left: G1,
+	right: G2,
+}
+
+impl<G1, G2> DisjointUnion<G1, G2> {
+	/// Creates the disjoint union of the given graphs.
+	pub fn new(left: G1, right: G2) -> Self {
+		Self { left, right }
+	}
+}
+
+impl<G1: Scc, G2: Scc> Scc for DisjointUnion<G1, G2> {
+	type Vertex = Either<G1::Vertex, G2::Vertex>;
+
+	fn vertices(&self) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+		self.left
+			.vertices()
+			.into_iter()
+			.map(Either::Left)
+			.chain(self.right.vertices().into_iter().map(Either::Right))
+	}
+
+	fn successors(&self, v: Self::Vertex) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+		let (left, right) = match v {
+			Either::Left(v) => (Some(self.left.successors(v)), None),
+			Either::Right(v) => (None, Some(self.right.successors(v))),
+		};
+
+		left.into_iter()
+			.flatten()
+			.map(Either::Left)
+			.chain(right.into_iter().flatten().map(Either::Right))
+	}
+}
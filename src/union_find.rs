@@ -0,0 +1,100 @@
+use std::{
+	collections::HashMap,
+	hash::{BuildHasher, Hash},
+};
+
+/// Disjoint-set forest over arbitrary vertices.
+pub struct UnionFind<V> {
+	/// Map from vertices to their index in `parent`.
+	indexes: HashMap<V, usize>,
+
+	/// Parent of each set element.
+	parent: Vec<usize>,
+
+	/// Upper bound of the height of each tree.
+	rank: Vec<u8>,
+
+	/// Number of disjoint sets.
+	sets: usize,
+}
+
+impl<V: Clone + Eq + Hash> UnionFind<V> {
+	pub fn new() -> Self {
+		Self {
+			indexes: HashMap::new(),
+			parent: Vec::new(),
+			rank: Vec::new(),
+			sets: 0,
+		}
+	}
+
+	/// Returns the index of the given vertex, inserting it in its own set if
+	/// necessary.
+	pub fn insert(&mut self, v: V) -> usize {
+		*self.indexes.entry(v).or_insert_with(|| {
+			let i = self.parent.len();
+			self.parent.push(i);
+			self.rank.push(0);
+			self.sets += 1;
+			i
+		})
+	}
+
+	/// Returns the representative element index of the set containing the
+	/// element with index `i`.
+	pub fn find(&mut self, mut i: usize) -> usize {
+		while self.parent[i] != i {
+			// Path halving.
+			self.parent[i] = self.parent[self.parent[i]];
+			i = self.parent[i]
+		}
+
+		i
+	}
+
+	/// Merges the sets containing `u` and `v`.
+	pub fn union(&mut self, u: V, v: V) {
+		let u = self.insert(u);
+		let v = self.insert(v);
+		let u = self.find(u);
+		let v = self.find(v);
+
+		if u != v {
+			self.sets -= 1;
+			match self.rank[u].cmp(&self.rank[v]) {
+				std::cmp::Ordering::Less => self.parent[u] = v,
+				std::cmp::Ordering::Greater => self.parent[v] = u,
+				std::cmp::Ordering::Equal => {
+					self.parent[v] = u;
+					self.rank[u] += 1
+				}
+			}
+		}
+	}
+
+	/// Returns the number of disjoint sets.
+	pub fn len(&self) -> usize {
+		self.sets
+	}
+
+	/// Maps each vertex to the index of its set, where sets are indexed from
+	/// `0` in order of first insertion of their elements.
+	pub fn into_vertex_map<S: BuildHasher + Default>(mut self) -> HashMap<V, usize, S> {
+		let mut set_index = vec![usize::MAX; self.parent.len()];
+		let mut sets = 0;
+		for i in 0..self.parent.len() {
+			let root = self.find(i);
+			if set_index[root] == usize::MAX {
+				set_index[root] = sets;
+				sets += 1
+			}
+
+			set_index[i] = set_index[root]
+		}
+
+		self.indexes
+			.into_iter()
+			.map(|(v, i)| (v, set_index[i]))
+			.collect()
+	}
+}
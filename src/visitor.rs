@@ -0,0 +1,55 @@
+use super::Scc;
+
+/// Strongly connected components visitor.
+///
+/// Callbacks are invoked by [`scc_visit`] as components are found, so that
+/// components can be consumed without being collected. All methods do nothing
+/// by default.
+pub trait SccVisitor<V> {
+	/// Called when a new component starts.
+	fn begin_component(&mut self) {}
+
+	/// Called for each vertex of the current component.
+	fn visit_vertex(&mut self, _v: V) {}
+
+	/// Called once all the vertices of the current component have been
+	/// visited.
+	fn end_component(&mut self) {}
+}
+
+/// Feeds the strongly connected components of `graph` to `visitor`.
+///
+/// Components are visited in the same order as
+/// [`Scc::strongly_connected_components`].
+///
+/// ```
+/// use scc_trait::{scc_visit, SccVisitor};
+///
+/// #[derive(Default)]
+/// struct Largest {
+///     current: usize,
+///     largest: usize,
+/// }
+///
+/// impl SccVisitor<usize> for Largest {
+///     fn begin_component(&mut self) {
+///         self.current = 0
+///     }
+///
+///     fn visit_vertex(&mut self, _v: usize) {
+///         self.current += 1
+///     }
+///
+///     fn end_component(&mut self) {
+///         self.largest = self.largest.max(self.current)
+///     }
+/// }
+///
+/// let graph = vec![vec![1], vec![2], vec![0], vec![]];
+/// let mut visitor = Largest::default();
+/// scc_visit(&graph, &mut visitor);
+/// assert_eq!(visitor.largest, 3);
+/// ```
+pub fn scc_visit<G: ?Sized + Scc, T: SccVisitor<G::Vertex>>(graph: &G, visitor: &mut T) {
+	crate::tarjan::visit(graph, visitor)
+}
@@ -0,0 +1,84 @@
+#![allow(dead_code)]
+use std::hash::{BuildHasher, Hash};
+
+use scc_trait::Components;
+
+/// Linear congruential pseudo-random number generator.
+pub struct Lcg(u64);
+
+impl Lcg {
+	pub fn new(seed: u64) -> Self {
+		Self(seed)
+	}
+
+	/// Returns a pseudo-random number lower than `n`.
+	pub fn below(&mut self, n: usize) -> usize {
+		self.0 = self
+			.0
+			.wrapping_mul(6364136223846793005)
+			.wrapping_add(1442695040888963407);
+		(self.0 >> 33) as usize % n
+	}
+}
+
+/// Pseudo-random adjacency list, where each vertex has at most `degree`
+/// successors.
+pub fn random_graph(len: usize, degree: usize, seed: u64) -> Vec<Vec<usize>> {
+	let mut rng = Lcg::new(seed);
+	(0..len)
+		.map(|_| (0..rng.below(degree + 1)).map(|_| rng.below(len)).collect())
+		.collect()
+}
+
+/// Components in a form independent of the component indices and of the
+/// order of the vertices.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Canonical<V> {
+	/// Sorted components, each sorted.
+	pub components: Vec<Vec<V>>,
+
+	/// Sorted condensation edges between distinct components, each component
+	/// being identified by its smallest vertex.
+	pub edges: Vec<(V, V)>,
+
+	/// Smallest vertex of each cyclic component, sorted.
+	pub cyclic: Vec<V>,
+}
+
+impl<V: Clone + Ord + Hash> Canonical<V> {
+	pub fn new<S: BuildHasher>(components: &Components<V, S>) -> Self {
+		let label = |i: usize| {
+			components
+				.get_by_index(i)
+				.unwrap()
+				.iter()
+				.min()
+				.unwrap()
+				.clone()
+		};
+
+		let mut list: Vec<Vec<V>> = components.iter().map(<[V]>::to_vec).collect();
+		for component in &mut list {
+			component.sort()
+		}
+		list.sort();
+
+		let mut edges: Vec<_> = components
+			.edges()
+			.map(|(i, j)| (label(i), label(j)))
+			.collect();
+		edges.sort();
+
+		let mut cyclic: Vec<_> = (0..components.len())
+			.filter(|&i| components.is_cyclic(i))
+			.map(label)
+			.collect();
+		cyclic.sort();
+
+		Self {
+			components: list,
+			edges,
+			cyclic,
+		}
+	}
+}
@@ -0,0 +1,54 @@
+use scc_trait::Scc;
+
+/// Inline successor container, in the style of `SmallVec` or `ArrayVec`.
+struct Inline {
+	len: usize,
+	items: [usize; 4],
+}
+
+impl Inline {
+	fn new(successors: &[usize]) -> Self {
+		let mut items = [0; 4];
+		items[..successors.len()].copy_from_slice(successors);
+		Self {
+			len: successors.len(),
+			items,
+		}
+	}
+}
+
+impl AsRef<[usize]> for Inline {
+	fn as_ref(&self) -> &[usize] {
+		&self.items[..self.len]
+	}
+}
+
+fn components<G: Scc<Vertex = usize>>(graph: &G) -> Vec<Vec<usize>> {
+	let mut result: Vec<Vec<usize>> = graph
+		.strongly_connected_components()
+		.iter()
+		.map(|component| {
+			let mut component = component.to_vec();
+			component.sort();
+			component
+		})
+		.collect();
+	result.sort();
+	result
+}
+
+#[test]
+fn successor_containers() {
+	let lists: Vec<Vec<usize>> = vec![vec![1], vec![2], vec![0, 3], vec![4], vec![3], vec![]];
+	let expected = components(&lists);
+	assert_eq!(expected, [vec![0, 1, 2], vec![3, 4], vec![5]]);
+
+	let slices: Vec<&[usize]> = lists.iter().map(Vec::as_slice).collect();
+	assert_eq!(components(&slices), expected);
+
+	let inline: Vec<Inline> = lists.iter().map(|list| Inline::new(list)).collect();
+	assert_eq!(components(&inline), expected);
+
+	let arrays: Vec<[usize; 1]> = vec![[1], [2], [0], [4], [3], [5]];
+	assert_eq!(components(&arrays), [vec![0, 1, 2], vec![3, 4], vec![5]]);
+}
@@ -0,0 +1,21 @@
+use std::collections::{HashMap, HashSet};
+
+use scc_trait::Scc;
+
+#[test]
+fn dangling_edges() {
+	// Vertex 3 only appears as a successor.
+	let graph: HashMap<usize, HashSet<usize>> =
+		HashMap::from([(0, [1].into()), (1, [0, 2].into()), (2, [3].into())]);
+
+	let components = graph.strongly_connected_components();
+	assert_eq!(components.len(), 3);
+	assert_eq!(components.get(&3), Some(&[3][..]));
+	assert!(components.same_component(&0, &1));
+
+	assert_eq!(graph.strongly_connected_components_pearce().len(), 3);
+	assert_eq!(graph.strongly_connected_components_gabow().len(), 3);
+	assert_eq!(graph.scc_iter().count(), 3);
+	assert!(graph.has_path(0, 3));
+	assert!(graph.has_cycle());
+}
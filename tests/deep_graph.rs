@@ -0,0 +1,64 @@
+use scc_trait::Scc;
+
+/// Long chain `0 → 1 → ... → n - 1`, closed by the edge `n - 1 → 0` if
+/// `cyclic` is set.
+struct Chain {
+	len: usize,
+	cyclic: bool,
+}
+
+impl Scc for Chain {
+	type Vertex = usize;
+
+	fn vertices(&self) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+		0..self.len
+	}
+
+	fn successors(&self, v: Self::Vertex) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+		if v + 1 < self.len {
+			Some(v + 1)
+		} else if self.cyclic {
+			Some(0)
+		} else {
+			None
+		}
+	}
+}
+
+#[test]
+fn deep_acyclic_chain() {
+	let components = Chain {
+		len: 200_000,
+		cyclic: false,
+	}
+	.strongly_connected_components();
+	assert_eq!(components.len(), 200_000)
+}
+
+#[test]
+fn deep_cyclic_chain() {
+	let components = Chain {
+		len: 200_000,
+		cyclic: true,
+	}
+	.strongly_connected_components();
+	assert_eq!(components.len(), 1);
+	assert!(components.is_cyclic(0))
+}
+
+#[test]
+fn deep_layers() {
+	// Every vertex also skips over its successor, which makes the number of
+	// paths to the last vertex exponential.
+	const LEN: usize = 100_000;
+	let graph: Vec<Vec<usize>> = (0..LEN)
+		.map(|v| (v + 1..LEN.min(v + 3)).collect())
+		.collect();
+
+	let components = graph.strongly_connected_components();
+	let layers = components.layers();
+	assert_eq!(layers.len(), LEN);
+	assert!(layers.iter().all(|layer| layer.len() == 1));
+	assert_eq!(components.condensation_diameter(), LEN - 1);
+	assert_eq!(components.depth_of(&(LEN - 1)), Some(LEN - 1))
+}
@@ -0,0 +1,39 @@
+mod common;
+
+use common::random_graph;
+use std::rc::Rc;
+
+use scc_trait::{FnGraph, Scc, SccWorkspace};
+
+#[test]
+fn dense_matches_generic() {
+	let mut workspace = SccWorkspace::new();
+
+	for seed in 0..20 {
+		let graph = random_graph(200, 3, seed);
+
+		// Hide the adjacency list behind a closure-based graph to use the
+		// generic hash map based algorithm.
+		let generic = FnGraph::new(|| 0..graph.len(), |v| graph[v].iter().copied());
+
+		let dense = graph.strongly_connected_components();
+		let expected = generic.strongly_connected_components();
+
+		assert_eq!(
+			dense.iter().collect::<Vec<_>>(),
+			expected.iter().collect::<Vec<_>>()
+		);
+		assert_eq!(dense.edges_sorted(), expected.edges_sorted());
+
+		for other in [
+			<&Vec<_> as Scc>::strongly_connected_components(&&graph),
+			Rc::new(graph.clone()).strongly_connected_components(),
+			workspace.compute_dense(&graph),
+		] {
+			assert_eq!(
+				other.iter().collect::<Vec<_>>(),
+				expected.iter().collect::<Vec<_>>()
+			);
+		}
+	}
+}
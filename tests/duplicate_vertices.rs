@@ -0,0 +1,42 @@
+use scc_trait::Scc;
+
+/// Graph whose `vertices` yields every vertex twice.
+struct Duplicated(Vec<Vec<usize>>);
+
+impl Scc for Duplicated {
+	type Vertex = usize;
+
+	fn vertices(&self) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+		(0..self.0.len()).chain((0..self.0.len()).rev())
+	}
+
+	fn successors(&self, v: Self::Vertex) -> impl '_ + IntoIterator<Item = Self::Vertex> {
+		self.0[v].iter().copied()
+	}
+}
+
+#[test]
+fn duplicate_vertices() {
+	let graph: Vec<Vec<usize>> = vec![
+		[1].into(),
+		[2].into(),
+		[0, 3].into(),
+		[4].into(),
+		[3].into(),
+		[].into(),
+	];
+
+	let expected = graph.strongly_connected_components();
+	let components = Duplicated(graph).strongly_connected_components();
+
+	assert_eq!(components.len(), expected.len());
+	for v in 0..6 {
+		let mut component = components.get(&v).unwrap().to_vec();
+		let mut expected_component = expected.get(&v).unwrap().to_vec();
+		component.sort_unstable();
+		expected_component.sort_unstable();
+		assert_eq!(component, expected_component)
+	}
+
+	assert_eq!(components.summary(), expected.summary())
+}
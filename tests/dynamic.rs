@@ -0,0 +1,57 @@
+mod common;
+
+use std::collections::{HashMap, HashSet};
+
+use common::{Canonical, Lcg};
+use scc_trait::{DynamicScc, Scc};
+
+#[test]
+fn dynamic_matches_recomputation() {
+	const VERTICES: usize = 12;
+
+	for seed in 0..20 {
+		let mut rng = Lcg::new(seed);
+		let mut dynamic = DynamicScc::new();
+		let mut graph: HashMap<usize, HashSet<usize>> = HashMap::new();
+
+		for _ in 0..200 {
+			let u = rng.below(VERTICES);
+			let v = rng.below(VERTICES);
+
+			match rng.below(10) {
+				0 => {
+					assert_eq!(dynamic.remove_vertex(&u), graph.remove(&u).is_some());
+					for successors in graph.values_mut() {
+						successors.remove(&u);
+					}
+				}
+				1..=3 => {
+					let removed = graph.get_mut(&u).is_some_and(|s| s.remove(&v));
+					assert_eq!(dynamic.remove_edge(&u, &v), removed)
+				}
+				_ => {
+					graph.entry(v).or_default();
+					let inserted = graph.entry(u).or_default().insert(v);
+					assert_eq!(dynamic.insert_edge(u, v), inserted)
+				}
+			}
+
+			assert_eq!(
+				Canonical::new(&dynamic.to_components()),
+				Canonical::new(&graph.strongly_connected_components())
+			);
+		}
+	}
+}
+
+#[test]
+fn non_copy_vertices() {
+	let mut dynamic = DynamicScc::new();
+	dynamic.insert_edge("a".to_string(), "b".to_string());
+	dynamic.insert_edge("b".to_string(), "a".to_string());
+	assert!(dynamic.same_component(&"a".to_string(), &"b".to_string()));
+
+	dynamic.remove_edge(&"b".to_string(), &"a".to_string());
+	assert!(!dynamic.same_component(&"a".to_string(), &"b".to_string()));
+	assert_eq!(dynamic.strongly_connected_components().len(), 2);
+}
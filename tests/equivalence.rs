@@ -0,0 +1,154 @@
+mod common;
+
+use std::collections::HashMap;
+
+use common::{random_graph, Canonical};
+use scc_trait::{Components, Scc, SccBidirectional};
+
+/// Random graph with self-loops, duplicate edges and vertices only appearing
+/// as successors.
+fn graph(seed: u64) -> HashMap<usize, Vec<usize>> {
+	const VERTICES: usize = 200;
+
+	random_graph(VERTICES, 3, seed)
+		.into_iter()
+		.enumerate()
+		.take(VERTICES - 10)
+		.map(|(v, mut successors)| {
+			if v % 7 == 0 {
+				successors.push(v)
+			}
+
+			if let Some(&w) = successors.first() {
+				successors.push(w)
+			}
+
+			(v, successors)
+		})
+		.collect()
+}
+
+/// Checks that both components have the same index for each component, and
+/// the same condensation edges.
+fn assert_same(components: &Components<usize>, expected: &Components<usize>) {
+	let sorted = |components: &Components<usize>| -> Vec<Vec<usize>> {
+		components
+			.iter()
+			.map(|c| {
+				let mut c = c.to_vec();
+				c.sort();
+				c
+			})
+			.collect()
+	};
+
+	assert_eq!(sorted(components), sorted(expected));
+	assert_eq!(components.edges_sorted(), expected.edges_sorted());
+	for i in 0..expected.len() {
+		assert_eq!(components.is_cyclic(i), expected.is_cyclic(i))
+	}
+}
+
+#[test]
+fn gabow_matches_tarjan() {
+	for seed in 0..20 {
+		let graph = graph(seed);
+		assert_same(
+			&graph.strongly_connected_components_gabow(),
+			&graph.strongly_connected_components(),
+		)
+	}
+}
+
+#[test]
+fn pearce_matches_tarjan() {
+	for seed in 0..20 {
+		let graph = graph(seed);
+		assert_same(
+			&graph.strongly_connected_components_pearce(),
+			&graph.strongly_connected_components(),
+		)
+	}
+}
+
+/// Adjacency list storing the predecessors of each vertex.
+struct Bidirectional {
+	successors: Vec<Vec<usize>>,
+	predecessors: Vec<Vec<usize>>,
+}
+
+impl Bidirectional {
+	fn new(successors: Vec<Vec<usize>>) -> Self {
+		let mut predecessors = vec![Vec::new(); successors.len()];
+		for (v, list) in successors.iter().enumerate() {
+			for &w in list {
+				predecessors[w].push(v)
+			}
+		}
+
+		Self {
+			successors,
+			predecessors,
+		}
+	}
+}
+
+impl Scc for Bidirectional {
+	type Vertex = usize;
+
+	fn vertices(&self) -> impl '_ + IntoIterator<Item = usize> {
+		0..self.successors.len()
+	}
+
+	fn successors(&self, v: usize) -> impl '_ + IntoIterator<Item = usize> {
+		self.successors[v].iter().copied()
+	}
+}
+
+impl SccBidirectional for Bidirectional {
+	fn predecessors(&self, v: usize) -> impl '_ + IntoIterator<Item = usize> {
+		self.predecessors[v].iter().copied()
+	}
+}
+
+#[test]
+fn kosaraju_matches_tarjan() {
+	for seed in 0..20 {
+		let graph = Bidirectional::new(random_graph(200, 3, seed));
+		let components = graph.strongly_connected_components_kosaraju();
+		let expected = graph.strongly_connected_components();
+		assert_eq!(Canonical::new(&components), Canonical::new(&expected));
+
+		// Components are reversed to be listed in reverse topological order,
+		// as with Tarjan's algorithm.
+		assert!(components.edges().all(|(i, j)| j < i));
+		assert!((0..200).all(|v| components.get(&v).unwrap().contains(&v)));
+	}
+}
+
+#[test]
+fn undirected_neighbors_match_weak_components() {
+	for seed in 0..20 {
+		let graph = Bidirectional::new(random_graph(200, 1, seed));
+
+		let mut visited = [false; 200];
+		let mut count = 0;
+		for root in 0..200 {
+			if !visited[root] {
+				count += 1;
+				visited[root] = true;
+				let mut stack = vec![root];
+				while let Some(v) = stack.pop() {
+					for w in graph.neighbors_undirected(v) {
+						if !visited[w] {
+							visited[w] = true;
+							stack.push(w)
+						}
+					}
+				}
+			}
+		}
+
+		assert_eq!(count, graph.connected_component_count())
+	}
+}
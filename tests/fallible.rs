@@ -0,0 +1,46 @@
+mod common;
+
+use std::cell::Cell;
+
+use common::random_graph;
+use scc_trait::{Scc, TryScc};
+
+/// Graph counting how many times the successors are fetched.
+struct Counting {
+	graph: Vec<Vec<usize>>,
+	fetched: Cell<usize>,
+}
+
+impl TryScc for Counting {
+	type Vertex = usize;
+	type Error = ();
+
+	fn vertices(&self) -> Result<impl '_ + IntoIterator<Item = usize>, ()> {
+		Ok(0..self.graph.len())
+	}
+
+	fn successors(&self, v: usize) -> Result<impl '_ + IntoIterator<Item = usize>, ()> {
+		self.fetched.set(self.fetched.get() + 1);
+		Ok(self.graph[v].iter().copied())
+	}
+}
+
+#[test]
+fn successors_fetched_once() {
+	for seed in 0..10 {
+		let graph = Counting {
+			graph: random_graph(100, 3, seed),
+			fetched: Cell::new(0),
+		};
+
+		let components = graph.try_strongly_connected_components().unwrap();
+		let expected = graph.graph.strongly_connected_components();
+		assert_eq!(graph.fetched.get(), 100);
+
+		assert_eq!(
+			components.iter().collect::<Vec<_>>(),
+			expected.iter().collect::<Vec<_>>()
+		);
+		assert_eq!(components.edges_sorted(), expected.edges_sorted());
+	}
+}
@@ -0,0 +1,52 @@
+use std::{rc::Rc, sync::Arc};
+
+use scc_trait::Scc;
+
+/// Graph overriding some of the provided methods with wrong results, to
+/// detect when they are used.
+struct Overriding(Vec<Vec<usize>>);
+
+impl Scc for Overriding {
+	type Vertex = usize;
+
+	fn vertices(&self) -> impl '_ + IntoIterator<Item = usize> {
+		0..self.0.len()
+	}
+
+	fn successors(&self, v: usize) -> impl '_ + IntoIterator<Item = usize> {
+		self.0[v].iter().copied()
+	}
+
+	fn has_cycle(&self) -> bool {
+		true
+	}
+
+	fn edge_count(&self) -> usize {
+		42
+	}
+
+	fn for_each_edge<F: FnMut(usize, usize)>(&self, mut f: F) {
+		f(0, 0)
+	}
+}
+
+fn check<G: Scc<Vertex = usize>>(graph: G) {
+	assert!(graph.has_cycle());
+	assert_eq!(graph.edge_count(), 42);
+
+	let mut edges = Vec::new();
+	graph.for_each_edge(|u, v| edges.push((u, v)));
+	assert_eq!(edges, [(0, 0)]);
+}
+
+#[test]
+fn forward_overrides() {
+	let graph = Overriding(vec![vec![1], vec![]]);
+	check(&graph);
+	let mut graph = Overriding(vec![vec![1], vec![]]);
+	let graph: &mut Overriding = &mut graph;
+	check(graph);
+	check(Box::new(Overriding(vec![vec![1], vec![]])));
+	check(Rc::new(Overriding(vec![vec![1], vec![]])));
+	check(Arc::new(Overriding(vec![vec![1], vec![]])));
+}
@@ -0,0 +1,16 @@
+#![cfg(feature = "fxhash")]
+use scc_trait::{Components, FxBuildHasher, Scc};
+
+#[test]
+fn default_hasher() {
+	let graph = vec![vec![1], vec![0, 2], vec![]];
+
+	// The default components use the Fx hasher.
+	let components: Components<usize, FxBuildHasher> = graph.strongly_connected_components();
+	assert_eq!(components.len(), 2);
+	assert!(components.same_component(&0, &1));
+
+	let components: Components<usize, FxBuildHasher> =
+		graph.strongly_connected_components_from([2]);
+	assert_eq!(components.len(), 1);
+}
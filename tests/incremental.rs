@@ -0,0 +1,47 @@
+mod common;
+
+use std::collections::{HashMap, HashSet};
+
+use common::{Canonical, Lcg};
+use scc_trait::{IncrementalScc, Scc};
+
+#[test]
+fn incremental_matches_recomputation() {
+	const VERTICES: usize = 30;
+
+	for seed in 0..20 {
+		let mut rng = Lcg::new(seed);
+		let mut incremental = IncrementalScc::new();
+		let mut graph: HashMap<usize, HashSet<usize>> = HashMap::new();
+
+		for _ in 0..100 {
+			let u = rng.below(VERTICES);
+			let v = rng.below(VERTICES);
+
+			let before = graph.strongly_connected_components().len();
+			graph.entry(v).or_default();
+			graph.entry(u).or_default().insert(v);
+			let expected = graph.strongly_connected_components();
+
+			// Components are merged exactly when the new edge closes a cycle
+			// between distinct components.
+			assert_eq!(incremental.insert_edge(u, v), expected.len() < before);
+			assert_eq!(incremental.len(), expected.len());
+
+			let components = incremental.to_components();
+			assert_eq!(Canonical::new(&components), Canonical::new(&expected));
+
+			// Components are listed in reverse topological order.
+			assert!(components.edges().all(|(i, j)| j < i));
+		}
+	}
+}
+
+#[test]
+fn non_copy_vertices() {
+	let mut incremental = IncrementalScc::new();
+	incremental.insert_edge("a".to_string(), "b".to_string());
+	assert!(incremental.insert_edge("b".to_string(), "a".to_string()));
+	assert!(incremental.same_component(&"a".to_string(), &"b".to_string()));
+	assert_eq!(incremental.to_components().len(), 1);
+}
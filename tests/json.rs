@@ -0,0 +1,33 @@
+#![cfg(feature = "serde")]
+use scc_trait::Scc;
+
+#[test]
+fn nested_json() {
+	let graph = vec![vec![1, 2], vec![3], vec![3], vec![4], vec![3]];
+	let components = graph.strongly_connected_components();
+	let root = components.vertex_component_index(&0).unwrap();
+
+	assert_eq!(
+		components.to_nested_json(root).unwrap(),
+		concat!(
+			r#"{"index":3,"successors":["#,
+			r#"{"index":1,"successors":[{"index":0,"successors":[],"vertices":[4,3]}],"vertices":[1]},"#,
+			r#"{"index":2,"successors":[{"ref":0}],"vertices":[2]}"#,
+			r#"],"vertices":[0]}"#
+		)
+	);
+	assert_eq!(components.to_nested_json(4), None);
+}
+
+#[test]
+fn deep_nested_json() {
+	const LEN: usize = 100_000;
+	let graph: Vec<Vec<usize>> = (0..LEN)
+		.map(|i| if i + 1 < LEN { vec![i + 1] } else { vec![] })
+		.collect();
+
+	let components = graph.strongly_connected_components();
+	let root = components.vertex_component_index(&0).unwrap();
+	let json = components.to_nested_json(root).unwrap();
+	assert_eq!(json.matches("\"index\"").count(), LEN);
+}
@@ -0,0 +1,32 @@
+use scc_trait::{AdjacencyMatrix, BitMatrix, Scc};
+
+#[test]
+fn padded_rows() {
+	let matrix = vec![
+		vec![false, true, false, false],
+		vec![true, false, false, false],
+		vec![false, true, false, false],
+	];
+
+	let components = AdjacencyMatrix::new(&matrix).strongly_connected_components();
+	assert_eq!(components.len(), 2);
+	assert!(components.same_component(&0, &1));
+
+	let bits = BitMatrix::from_rows(&matrix);
+	assert_eq!(bits.len(), 3);
+	assert_eq!(bits.strongly_connected_components().len(), 2);
+}
+
+#[test]
+#[should_panic = "vertex out of bounds"]
+fn adjacency_matrix_out_of_bounds() {
+	let matrix = vec![vec![false, true, true], vec![true, false, false]];
+	AdjacencyMatrix::new(&matrix);
+}
+
+#[test]
+#[should_panic = "vertex out of bounds"]
+fn bit_matrix_out_of_bounds() {
+	let matrix = vec![vec![false, true, true], vec![true, false, false]];
+	BitMatrix::from_rows(&matrix);
+}
@@ -0,0 +1,42 @@
+#![cfg(feature = "memmap")]
+use std::{fs, path::PathBuf};
+
+use scc_trait::{MmapCsrGraph, Scc};
+
+fn write_u32s(path: &PathBuf, values: &[u32]) {
+	let bytes: Vec<u8> = values.iter().flat_map(|v| v.to_ne_bytes()).collect();
+	fs::write(path, bytes).unwrap()
+}
+
+#[test]
+fn mmap_csr_graph() {
+	let dir = std::env::temp_dir().join(format!("scc-trait-mmap-{}", std::process::id()));
+	fs::create_dir_all(&dir).unwrap();
+	let offsets_path = dir.join("offsets");
+	let targets_path = dir.join("targets");
+
+	// 0 → 1 → 2 → 0, 2 → 3, 3 → 4 → 3
+	write_u32s(&offsets_path, &[0, 1, 2, 4, 5, 6]);
+	write_u32s(&targets_path, &[1, 2, 0, 3, 4, 3]);
+
+	let graph = unsafe { MmapCsrGraph::open(&offsets_path, &targets_path) }.unwrap();
+	assert_eq!(graph.vertex_count(), 5);
+
+	let components = graph.strongly_connected_components();
+	assert_eq!(components.len(), 2);
+	assert_eq!(
+		components.vertex_component_index(&0),
+		components.vertex_component_index(&2)
+	);
+	assert_eq!(
+		components.vertex_component_index(&3),
+		components.vertex_component_index(&4)
+	);
+	assert_ne!(
+		components.vertex_component_index(&0),
+		components.vertex_component_index(&3)
+	);
+
+	drop(graph);
+	fs::remove_dir_all(&dir).unwrap()
+}
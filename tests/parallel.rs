@@ -0,0 +1,42 @@
+#![cfg(feature = "rayon")]
+mod common;
+
+use std::collections::HashMap;
+
+use common::{random_graph, Canonical};
+use scc_trait::Scc;
+
+#[test]
+fn parallel_matches_tarjan() {
+	const VERTICES: usize = 300;
+
+	for seed in 0..20 {
+		let list = random_graph(VERTICES, 4, seed);
+
+		// The last vertices only appear as successors. Self-loops and
+		// duplicate edges are added to the others.
+		let graph: HashMap<usize, Vec<usize>> = list
+			.into_iter()
+			.enumerate()
+			.take(VERTICES - 10)
+			.map(|(v, mut successors)| {
+				if v % 7 == 0 {
+					successors.push(v)
+				}
+
+				if let Some(&w) = successors.first() {
+					successors.push(w)
+				}
+
+				(v, successors)
+			})
+			.collect();
+
+		let parallel = graph.par_strongly_connected_components();
+		let expected = graph.strongly_connected_components();
+		assert_eq!(Canonical::new(&parallel), Canonical::new(&expected));
+
+		// Components are listed in reverse topological order.
+		assert!(parallel.edges().all(|(i, j)| j < i));
+	}
+}
@@ -0,0 +1,12 @@
+use scc_trait::Components;
+
+#[test]
+fn empty_component_period() {
+	let graph = vec![vec![1], vec![0]];
+	let mut components = Components::from_partition(&graph, [vec![0, 1]]);
+	let empty = components.push_component(Vec::new());
+
+	assert_eq!(components.period(&graph, 0), 2);
+	assert_eq!(components.period(&graph, empty), 0);
+	assert!(!components.is_aperiodic(&graph, empty));
+}
@@ -0,0 +1,57 @@
+mod common;
+
+use common::random_graph;
+use scc_trait::Scc;
+
+#[test]
+fn resume_matches_one_shot() {
+	for seed in 0..10 {
+		let graph = random_graph(100, 3, seed);
+		let expected = graph.strongly_connected_components();
+
+		for max_steps in [1, 2, 3, 7] {
+			let mut progress = graph.strongly_connected_components_resumable();
+			let components = loop {
+				if let Some(components) = graph.resume(&mut progress, max_steps) {
+					break components;
+				}
+			};
+
+			assert_eq!(
+				components.iter().collect::<Vec<_>>(),
+				expected.iter().collect::<Vec<_>>()
+			);
+			assert_eq!(components.edges_sorted(), expected.edges_sorted());
+			assert_eq!(progress.visited_vertices(), 0);
+		}
+	}
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_round_trip() {
+	use scc_trait::SccProgress;
+
+	for seed in 0..10 {
+		let graph = random_graph(100, 3, seed);
+		let expected = graph.strongly_connected_components();
+
+		// Serialize and deserialize the state between every resumption.
+		let mut json =
+			serde_json::to_string(&graph.strongly_connected_components_resumable()).unwrap();
+		let components = loop {
+			let mut progress: SccProgress<usize> = serde_json::from_str(&json).unwrap();
+			if let Some(components) = graph.resume(&mut progress, 5) {
+				break components;
+			}
+
+			json = serde_json::to_string(&progress).unwrap();
+		};
+
+		assert_eq!(
+			components.iter().collect::<Vec<_>>(),
+			expected.iter().collect::<Vec<_>>()
+		);
+		assert_eq!(components.edges_sorted(), expected.edges_sorted());
+	}
+}
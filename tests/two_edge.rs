@@ -0,0 +1,42 @@
+use scc_trait::{strong_bridges, two_edge_strongly_connected_components, Scc};
+
+#[test]
+fn two_edge_components() {
+	// Two 2-edge strongly connected triangles, linked by a strong bridge in
+	// each direction, and an isolated vertex with a self-loop.
+	let graph = vec![
+		vec![1, 2],
+		vec![0, 2],
+		vec![0, 1, 3],
+		vec![2, 4, 5],
+		vec![3, 5],
+		vec![3, 4],
+		vec![6],
+	];
+
+	let result = two_edge_strongly_connected_components(&graph);
+	assert_eq!(result.edges().count(), 0);
+	assert!(result.same_component(&0, &2));
+	assert!(!result.same_component(&2, &3));
+	assert_eq!(result.get(&6), Some(&[6][..]));
+
+	let mut components: Vec<Vec<usize>> = result.iter().map(<[usize]>::to_vec).collect();
+	for component in &mut components {
+		component.sort()
+	}
+	components.sort();
+	assert_eq!(components, [vec![0, 1, 2], vec![3, 4, 5], vec![6]]);
+
+	for component in &components {
+		let subgraph = graph.node_filtered(|v| component.contains(v));
+		assert!(subgraph.is_strongly_connected());
+		assert!(strong_bridges(&subgraph).is_empty());
+		assert_eq!(subgraph.vertices().into_iter().count(), component.len());
+	}
+
+	let bridges = strong_bridges(&graph);
+	assert_eq!(bridges.len(), 2);
+	for (u, v) in bridges {
+		assert!(!components.iter().any(|c| c.contains(&u) && c.contains(&v)))
+	}
+}
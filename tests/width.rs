@@ -0,0 +1,39 @@
+mod common;
+
+use common::random_graph;
+use scc_trait::Scc;
+
+#[test]
+fn width_matches_brute_force() {
+	for seed in 0..50 {
+		let graph = random_graph(12, 2, seed);
+		let components = graph.strongly_connected_components();
+		let closure = components.transitive_closure();
+		let n = components.len();
+
+		// Largest set of pairwise unreachable components.
+		let expected = (0u32..1 << n)
+			.filter(|set| {
+				(0..n).all(|i| {
+					set & (1 << i) == 0
+						|| (0..n).all(|j| i == j || set & (1 << j) == 0 || !closure.reaches(i, j))
+				})
+			})
+			.map(u32::count_ones)
+			.max()
+			.unwrap() as usize;
+
+		assert_eq!(components.condensation_width(), expected);
+	}
+}
+
+#[test]
+fn width_of_long_path() {
+	const LEN: usize = 1000;
+	let graph: Vec<Vec<usize>> = (0..LEN)
+		.map(|i| if i + 1 < LEN { vec![i + 1] } else { vec![] })
+		.collect();
+
+	let components = graph.strongly_connected_components();
+	assert_eq!(components.condensation_width(), 1);
+}